@@ -0,0 +1,90 @@
+use std::io::BufRead;
+use std::io::Seek;
+use crate::options::*;
+use crate::traits::*;
+
+impl<R: BufRead + Seek> ReadLastLinesIntoStringsOnSelf for R {
+
+    /// Read the last n lines into Vec<String>; keep each line end `\n` or `\r\n`.
+    /// 
+    /// This seeks backwards from the end, so it reads about as many
+    /// bytes as the last `n` lines, rather than the whole source.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let mut buf_reader = BufReader::new(file);
+    /// let strings: Vec<String> = buf_reader.read_last_lines_into_vec_string(200).unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_last_lines_into_vec_string(self, n: usize) -> crate::Result<Vec<String>> {
+        ReadLinesOptions::new().read_last_into(self, n)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
+
+    fn sut(path_str: &str) -> BufReader<File> {
+        BufReader::new(File::open(path_str).unwrap())
+    }
+
+    #[test]
+    fn with_lf() {
+        let buf_reader = sut("example.txt");
+        assert_eq!(
+            buf_reader.read_last_lines_into_vec_string(1).unwrap(),
+            vec![String::from("ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let buf_reader = sut("example-with-crlf.txt");
+        assert_eq!(
+            buf_reader.read_last_lines_into_vec_string(1).unwrap(),
+            vec![String::from("ipsum\r\n")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let buf_reader = sut("example-with-indent.txt");
+        assert_eq!(
+            buf_reader.read_last_lines_into_vec_string(1).unwrap(),
+            vec![String::from("    ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_more_than_all_lines() {
+        let buf_reader = sut("example.txt");
+        assert_eq!(buf_reader.read_last_lines_into_vec_string(3).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn with_crlf_and_without_final_newline() {
+        let buf_reader = Cursor::new("lorem\r\nipsum\r\n\r\ndolor");
+        assert_eq!(
+            buf_reader.read_last_lines_into_vec_string(3).unwrap(),
+            vec![String::from("ipsum\r\n"), String::from("\r\n"), String::from("dolor")]
+        );
+    }
+
+    #[test]
+    fn with_zero() {
+        let buf_reader = sut("example.txt");
+        assert!(buf_reader.read_last_lines_into_vec_string(0).unwrap().is_empty());
+    }
+
+}
@@ -0,0 +1,83 @@
+use std::io::BufRead;
+use std::io::Seek;
+use crate::options::*;
+use crate::traits::*;
+
+impl<R: BufRead + Seek> ReadLastLinesIntoStringsWithTrimOnSelf for R {
+
+    /// Read the last n lines into Vec<String>; trim each line.
+    /// 
+    /// This seeks backwards from the end, so it reads about as many
+    /// bytes as the last `n` lines, rather than the whole source.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let mut buf_reader = BufReader::new(file);
+    /// let strings: Vec<String> = buf_reader.read_last_lines_into_vec_string_with_trim(200).unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_last_lines_into_vec_string_with_trim(self, n: usize) -> crate::Result<Vec<String>> {
+        ReadLinesOptions::new()
+            .endings(Endings::Clip)
+            .whitespace(Whitespace::Trim)
+            .read_last_into(self, n)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::BufReader;
+
+    fn sut(path_str: &str) -> BufReader<File> {
+        BufReader::new(File::open(path_str).unwrap())
+    }
+
+    #[test]
+    fn with_lf() {
+        let buf_reader = sut("example.txt");
+        assert_eq!(
+            buf_reader.read_last_lines_into_vec_string_with_trim(1).unwrap(),
+            vec![String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let buf_reader = sut("example-with-crlf.txt");
+        assert_eq!(
+            buf_reader.read_last_lines_into_vec_string_with_trim(1).unwrap(),
+            vec![String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let buf_reader = sut("example-with-indent.txt");
+        assert_eq!(
+            buf_reader.read_last_lines_into_vec_string_with_trim(1).unwrap(),
+            vec![String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_more_than_all_lines() {
+        let buf_reader = sut("example.txt");
+        assert_eq!(buf_reader.read_last_lines_into_vec_string_with_trim(3).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn with_zero() {
+        let buf_reader = sut("example.txt");
+        assert!(buf_reader.read_last_lines_into_vec_string_with_trim(0).unwrap().is_empty());
+    }
+
+}
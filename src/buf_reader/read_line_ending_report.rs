@@ -0,0 +1,110 @@
+use std::io::BufRead;
+use crate::line_ending_report::LineEndingReport;
+use crate::line_terminators::LineTerminators;
+use crate::options::ReadLinesOptions;
+use crate::traits::*;
+
+impl<R: BufRead> ReadLineEndingReportOnSelf for R {
+
+    /// Read lines into a LineEndingReport; count each line end `\n`, `\r\n`, or `\r`.
+    /// 
+    /// This reads bytes, so the source does not need to be valid UTF-8.
+    /// 
+    /// For other line terminators, or limits, see `ReadLinesOptions::read_line_ending_report`.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use read_lines_into::line_ending_report::LineEndingReport;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let mut buf_reader = BufReader::new(file);
+    /// let report: LineEndingReport = buf_reader.read_line_ending_report().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_line_ending_report(self) -> crate::Result<LineEndingReport> {
+        ReadLinesOptions::new()
+            .terminators(LineTerminators::DEFAULT | LineTerminators::CR)
+            .read_line_ending_report(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line_ending::LineEnding;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
+
+    fn sut(path_str: &str) -> BufReader<File> {
+        BufReader::new(File::open(path_str).unwrap())
+    }
+
+    #[test]
+    fn with_lf() {
+        let buf_reader = sut("example.txt");
+        assert_eq!(
+            buf_reader.read_line_ending_report().unwrap(),
+            LineEndingReport { lf: 2, dominant: Some(LineEnding::Lf), ..Default::default() }
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let buf_reader = sut("example-with-crlf.txt");
+        assert_eq!(
+            buf_reader.read_line_ending_report().unwrap(),
+            LineEndingReport { crlf: 2, dominant: Some(LineEnding::CrLf), ..Default::default() }
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let buf_reader = sut("example-with-indent.txt");
+        assert_eq!(
+            buf_reader.read_line_ending_report().unwrap(),
+            LineEndingReport { lf: 2, dominant: Some(LineEnding::Lf), ..Default::default() }
+        );
+    }
+
+    #[test]
+    fn with_cr() {
+        let buf_reader = sut("example-with-cr.txt");
+        assert_eq!(
+            buf_reader.read_line_ending_report().unwrap(),
+            LineEndingReport { cr: 2, dominant: Some(LineEnding::Cr), ..Default::default() }
+        );
+    }
+
+    #[test]
+    fn with_mixed_endings() {
+        let buf_reader = sut("example-with-mixed-endings.txt");
+        assert_eq!(
+            buf_reader.read_line_ending_report().unwrap(),
+            LineEndingReport { lf: 2, crlf: 1, dominant: Some(LineEnding::Lf), is_mixed: true, ..Default::default() }
+        );
+    }
+
+    #[test]
+    fn with_invalid_utf8_and_without_final_newline() {
+        let cursor = Cursor::new(b"lorem\xff\r\nipsum\r\ndolor\rsit".to_vec());
+        assert_eq!(
+            cursor.read_line_ending_report().unwrap(),
+            LineEndingReport {
+                lf: 0,
+                crlf: 2,
+                cr: 1,
+                none_at_eof: true,
+                dominant: Some(LineEnding::CrLf),
+                is_mixed: true,
+                ..Default::default()
+            }
+        );
+    }
+
+}
@@ -0,0 +1,95 @@
+use std::io::BufRead;
+use crate::options::*;
+use crate::traits::*;
+
+impl<R: BufRead> ReadLinesExtendOnSelf for R {
+
+    /// Read lines and append them to an existing collection of strings,
+    /// such as Vec<String> or HashSet<String>; clip each line end, as in `read_lines_into`.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let mut strings: Vec<String> = vec![String::from("header")];
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let mut buf_reader = BufReader::new(file);
+    /// buf_reader.read_lines_extend(&mut strings).unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately, and the lines
+    /// before the error will already be in the collection.
+    /// 
+    fn read_lines_extend(self, collection: &mut impl Extend<String>) -> crate::Result<()> {
+        ReadLinesOptions::new()
+            .endings(Endings::Clip)
+            .read_extend(self, collection)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
+
+    fn sut(path_str: &str) -> BufReader<File> {
+        BufReader::new(File::open(path_str).unwrap())
+    }
+
+    fn collection() -> Vec<String> {
+        vec![String::from("header")]
+    }
+
+    #[test]
+    fn with_lf() {
+        let buf_reader = sut("example.txt");
+        let mut strings = collection();
+        buf_reader.read_lines_extend(&mut strings).unwrap();
+        assert_eq!(
+            strings,
+            vec![String::from("header"), String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let buf_reader = sut("example-with-crlf.txt");
+        let mut strings = collection();
+        buf_reader.read_lines_extend(&mut strings).unwrap();
+        assert_eq!(
+            strings,
+            vec![String::from("header"), String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let buf_reader = sut("example-with-indent.txt");
+        let mut strings = collection();
+        buf_reader.read_lines_extend(&mut strings).unwrap();
+        assert_eq!(
+            strings,
+            vec![String::from("header"), String::from("    lorem"), String::from("    ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_set() {
+        let mut set = HashSet::from([String::from("lorem")]);
+        Cursor::new("lorem\nipsum\n").read_lines_extend(&mut set).unwrap();
+        assert_eq!(set, HashSet::from([String::from("lorem"), String::from("ipsum")]));
+    }
+
+    #[test]
+    fn with_error_keeps_lines_before_error() {
+        let mut strings = collection();
+        assert!(Cursor::new(b"lorem\nipsum\xff\n".to_vec()).read_lines_extend(&mut strings).is_err());
+        assert_eq!(strings, vec![String::from("header"), String::from("lorem")]);
+    }
+
+}
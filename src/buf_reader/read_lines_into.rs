@@ -0,0 +1,100 @@
+use std::io::BufRead;
+use crate::options::*;
+use crate::traits::*;
+
+impl<R: BufRead> ReadLinesIntoOnSelf for R {
+
+    /// Read lines into any collection of strings, such as HashSet<String>,
+    /// BTreeSet<String>, or VecDeque<String>; clip each line end.
+    /// 
+    /// Clipping means that lines that differ only in their line end,
+    /// such as `lorem\n` and `lorem\r\n`, are the same string.
+    /// To keep line ends, or to trim, use `read_lines_into_with_options`.
+    /// 
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let mut buf_reader = BufReader::new(file);
+    /// let set = buf_reader.read_lines_into::<BTreeSet<String>>().unwrap();
+    /// ```
+    /// 
+    /// For a collection of `Box<str>` or `Arc<str>`, map the lazy iterator:
+    /// 
+    /// ```
+    /// use std::io::Cursor;
+    /// use std::sync::Arc;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let cursor = Cursor::new("lorem\nipsum\n");
+    /// let strs = cursor.lines_with_clip().map(|x| x.map(Arc::from)).collect::<read_lines_into::Result<Vec<Arc<str>>>>().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into<C: FromIterator<String>>(self) -> crate::Result<C> {
+        ReadLinesOptions::new()
+            .endings(Endings::Clip)
+            .read_into(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+    use std::collections::HashSet;
+    use std::collections::VecDeque;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
+
+    fn sut(path_str: &str) -> BufReader<File> {
+        BufReader::new(File::open(path_str).unwrap())
+    }
+
+    #[test]
+    fn with_lf() {
+        let buf_reader = sut("example.txt");
+        assert_eq!(
+            buf_reader.read_lines_into::<VecDeque<String>>().unwrap(),
+            VecDeque::from([String::from("lorem"), String::from("ipsum")])
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let buf_reader = sut("example-with-crlf.txt");
+        assert_eq!(
+            buf_reader.read_lines_into::<VecDeque<String>>().unwrap(),
+            VecDeque::from([String::from("lorem"), String::from("ipsum")])
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let buf_reader = sut("example-with-indent.txt");
+        assert_eq!(
+            buf_reader.read_lines_into::<VecDeque<String>>().unwrap(),
+            VecDeque::from([String::from("    lorem"), String::from("    ipsum")])
+        );
+    }
+
+    #[test]
+    fn with_sets() {
+        let text = "lorem\nipsum\nlorem\r\n";
+        assert_eq!(
+            Cursor::new(text).read_lines_into::<HashSet<String>>().unwrap(),
+            HashSet::from([String::from("lorem"), String::from("ipsum")])
+        );
+        assert_eq!(
+            Cursor::new(text).read_lines_into::<BTreeSet<String>>().unwrap().into_iter().collect::<Vec<String>>(),
+            vec![String::from("ipsum"), String::from("lorem")]
+        );
+    }
+
+}
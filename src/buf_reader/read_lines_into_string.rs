@@ -1,9 +1,8 @@
-use std::fs::File;
-use std::io::BufReader;
 use std::io::BufRead;
+use crate::options::*;
 use crate::traits::*;
 
-impl ReadLinesIntoStringOnSelf for BufReader<File> {
+impl<R: BufRead> ReadLinesIntoStringOnSelf for R {
 
     /// Read lines into String; keep each line end `\n` or `\r\n`.
     /// 
@@ -19,17 +18,8 @@ impl ReadLinesIntoStringOnSelf for BufReader<File> {
     /// 
     /// Any error will return immediately.
     /// 
-    fn read_lines_into_string(mut self) -> ::std::io::Result<String> {
-        let mut string = String::new();
-        let mut buf = String::new();
-        let mut n: usize;
-        loop {
-            n = self.read_line(&mut buf)?;
-            if n == 0 { break; }
-            string.push_str(&buf);
-            buf.clear();
-        }
-        Ok(string)
+    fn read_lines_into_string(self) -> crate::Result<String> {
+        ReadLinesOptions::new().read_into(self)
     }
 
 }
@@ -37,6 +27,9 @@ impl ReadLinesIntoStringOnSelf for BufReader<File> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
 
     fn sut(path_str: &str) -> BufReader<File> {
         BufReader::new(File::open(path_str).unwrap())
@@ -69,4 +62,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn with_cursor() {
+        let cursor = Cursor::new("lorem\nipsum\r\ndolor");
+        assert_eq!(
+            cursor.read_lines_into_string().unwrap(),
+            "lorem\nipsum\r\ndolor"
+        );
+    }
+
+    #[test]
+    fn with_mut_ref() {
+        let mut buf_reader = sut("example.txt");
+        assert!(!(&mut buf_reader).read_lines_into_string().unwrap().is_empty());
+        assert!(buf_reader.read_lines_into_string().unwrap().is_empty());
+    }
+
 }
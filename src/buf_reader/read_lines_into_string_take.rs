@@ -0,0 +1,102 @@
+use std::io::BufRead;
+use crate::options::*;
+use crate::traits::*;
+
+impl<R: BufRead> ReadLinesIntoStringTakeOnMutSelf for R {
+
+    /// Read at most n lines into String; keep each line end `\n` or `\r\n`.
+    /// 
+    /// The reader is borrowed, not consumed, and is positioned
+    /// right after the last line read, so it can be used again.
+    /// 
+    /// For other line terminators, or limits, see `ReadLinesOptions::take`.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let mut buf_reader = BufReader::new(file);
+    /// let head: String = buf_reader.read_lines_into_string_take(1).unwrap();
+    /// let rest: String = buf_reader.read_lines_into_string().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_string_take(&mut self, n: usize) -> crate::Result<String> {
+        ReadLinesOptions::new().take(n).read_into(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
+
+    fn sut(path_str: &str) -> BufReader<File> {
+        BufReader::new(File::open(path_str).unwrap())
+    }
+
+    #[test]
+    fn with_lf() {
+        let mut buf_reader = sut("example.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_string_take(1).unwrap(),
+            "lorem\n"
+        );
+        assert_eq!(
+            buf_reader.read_lines_into_string().unwrap(),
+            "ipsum\n"
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let mut buf_reader = sut("example-with-crlf.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_string_take(1).unwrap(),
+            "lorem\r\n"
+        );
+        assert_eq!(
+            buf_reader.read_lines_into_string().unwrap(),
+            "ipsum\r\n"
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let mut buf_reader = sut("example-with-indent.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_string_take(1).unwrap(),
+            "    lorem\n"
+        );
+        assert_eq!(
+            buf_reader.read_lines_into_string().unwrap(),
+            "    ipsum\n"
+        );
+    }
+
+    #[test]
+    fn with_more_than_available() {
+        let mut cursor = Cursor::new("lorem\nipsum");
+        assert_eq!(
+            cursor.read_lines_into_string_take(3).unwrap(),
+            "lorem\nipsum"
+        );
+        assert_eq!(cursor.read_lines_into_string_take(3).unwrap(), "");
+    }
+
+    #[test]
+    fn with_invalid_utf8() {
+        let mut cursor = Cursor::new(b"lorem\nips\xffum\n\n".to_vec());
+        assert_eq!(
+            cursor.read_lines_into_string_take(2).unwrap_err().to_string(),
+            "line 2: stream did not contain valid UTF-8 at byte offset 9"
+        );
+    }
+
+}
@@ -0,0 +1,98 @@
+use std::io::BufRead;
+use crate::options::*;
+use crate::traits::*;
+
+impl<R: BufRead> ReadLinesIntoStringUntilBlankOnMutSelf for R {
+
+    /// Read lines into String until a blank line; keep each line end `\n` or `\r\n`.
+    /// 
+    /// A blank line is a line that is empty apart from its line end.
+    /// The blank line is consumed, but is not included in the result.
+    /// 
+    /// The reader is borrowed, not consumed, and is positioned
+    /// right after the blank line, so it can be used again.
+    /// 
+    /// For other line terminators, or limits, see `ReadLinesOptions::until_blank`.
+    /// 
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let mut cursor = Cursor::new("Host: example.com\r\n\r\nbody\r\n");
+    /// let head: String = cursor.read_lines_into_string_until_blank().unwrap();
+    /// let body: String = cursor.read_lines_into_string().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_string_until_blank(&mut self) -> crate::Result<String> {
+        ReadLinesOptions::new().until_blank(true).read_into(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
+
+    fn sut(path_str: &str) -> BufReader<File> {
+        BufReader::new(File::open(path_str).unwrap())
+    }
+
+    #[test]
+    fn with_lf() {
+        let mut buf_reader = sut("example.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_string_until_blank().unwrap(),
+            "lorem\nipsum\n"
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let mut buf_reader = sut("example-with-crlf.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_string_until_blank().unwrap(),
+            "lorem\r\nipsum\r\n"
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let mut buf_reader = sut("example-with-indent.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_string_until_blank().unwrap(),
+            "    lorem\n    ipsum\n"
+        );
+    }
+
+    #[test]
+    fn with_blank_line() {
+        let mut cursor = Cursor::new("lorem\r\n\r\nipsum\n\ndolor\n");
+        assert_eq!(
+            cursor.read_lines_into_string_until_blank().unwrap(),
+            "lorem\r\n"
+        );
+        assert_eq!(
+            cursor.read_lines_into_string_until_blank().unwrap(),
+            "ipsum\n"
+        );
+        assert_eq!(
+            cursor.read_lines_into_string().unwrap(),
+            "dolor\n"
+        );
+    }
+
+    #[test]
+    fn with_invalid_utf8() {
+        let mut cursor = Cursor::new(b"lorem\nips\xffum\n\n".to_vec());
+        assert_eq!(
+            cursor.read_lines_into_string_until_blank().unwrap_err().to_string(),
+            "line 2: stream did not contain valid UTF-8 at byte offset 9"
+        );
+    }
+
+}
@@ -1,9 +1,8 @@
-use std::fs::File;
-use std::io::BufReader;
 use std::io::BufRead;
+use crate::options::*;
 use crate::traits::*;
 
-impl ReadLinesIntoStringWithClipOnSelf for BufReader<File> {
+impl<R: BufRead> ReadLinesIntoStringWithClipOnSelf for R {
 
     /// Read lines into String; clip each line end `\n` or `\r\n`.
     /// 
@@ -19,14 +18,10 @@ impl ReadLinesIntoStringWithClipOnSelf for BufReader<File> {
     /// 
     /// Any error will return immediately.
     /// 
-    fn read_lines_into_string_with_clip(self) -> ::std::io::Result<String> {
-        let mut string = String::new();
-        let lines = self.lines();
-        for line in lines {
-            let x = line?;
-            string.push_str(&x);
-        }
-        Ok(string)
+    fn read_lines_into_string_with_clip(self) -> crate::Result<String> {
+        ReadLinesOptions::new()
+            .endings(Endings::Clip)
+            .read_into(self)
     }
 
 }
@@ -34,6 +29,9 @@ impl ReadLinesIntoStringWithClipOnSelf for BufReader<File> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
 
     fn sut(path_str: &str) -> BufReader<File> {
         BufReader::new(File::open(path_str).unwrap())
@@ -43,8 +41,8 @@ mod tests {
     fn with_lf() {
         let buf_reader = sut("example.txt");
         assert_eq!(
-            buf_reader.read_lines_into_string().unwrap(),
-            "lorem\nipsum\n"
+            buf_reader.read_lines_into_string_with_clip().unwrap(),
+            "loremipsum"
         );
     }
 
@@ -52,8 +50,8 @@ mod tests {
     fn with_crlf() {
         let buf_reader = sut("example-with-crlf.txt");
         assert_eq!(
-            buf_reader.read_lines_into_string().unwrap(),
-            "lorem\r\nipsum\r\n"
+            buf_reader.read_lines_into_string_with_clip().unwrap(),
+            "loremipsum"
         );
     }
 
@@ -61,9 +59,25 @@ mod tests {
     fn with_indent() {
         let buf_reader = sut("example-with-indent.txt");
         assert_eq!(
-            buf_reader.read_lines_into_string().unwrap(),
-            "    lorem\n    ipsum\n"
+            buf_reader.read_lines_into_string_with_clip().unwrap(),
+            "    lorem    ipsum"
         );
     }
 
+    #[test]
+    fn with_cursor() {
+        let cursor = Cursor::new("lorem\nipsum\r\ndolor");
+        assert_eq!(
+            cursor.read_lines_into_string_with_clip().unwrap(),
+            "loremipsumdolor"
+        );
+    }
+
+    #[test]
+    fn with_mut_ref() {
+        let mut buf_reader = sut("example.txt");
+        assert!(!(&mut buf_reader).read_lines_into_string_with_clip().unwrap().is_empty());
+        assert!(buf_reader.read_lines_into_string_with_clip().unwrap().is_empty());
+    }
+
 }
@@ -0,0 +1,81 @@
+use std::io::BufRead;
+use crate::line_ending::NewlineStyle;
+use crate::options::*;
+use crate::traits::*;
+
+impl<R: BufRead> ReadLinesIntoStringWithNormalizedEndingsOnSelf for R {
+
+    /// Read lines into String; replace each line end `\n` or `\r\n` with the given newline style, LF or CRLF.
+    /// 
+    /// A last line without a line end stays without a line end,
+    /// so the result ends with a newline if and only if the source does.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use read_lines_into::NewlineStyle;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example-with-crlf.txt").unwrap();
+    /// let mut buf_reader = BufReader::new(file);
+    /// let string: String = buf_reader.read_lines_into_string_with_normalized_endings(NewlineStyle::Lf).unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_string_with_normalized_endings(self, style: NewlineStyle) -> crate::Result<String> {
+        ReadLinesOptions::new()
+            .endings(Endings::Normalize(style))
+            .read_into(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
+
+    fn sut(path_str: &str) -> BufReader<File> {
+        BufReader::new(File::open(path_str).unwrap())
+    }
+
+    #[test]
+    fn with_lf() {
+        let buf_reader = sut("example.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_string_with_normalized_endings(NewlineStyle::CrLf).unwrap(),
+            "lorem\r\nipsum\r\n"
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let buf_reader = sut("example-with-crlf.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_string_with_normalized_endings(NewlineStyle::Lf).unwrap(),
+            "lorem\nipsum\n"
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let buf_reader = sut("example-with-indent.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_string_with_normalized_endings(NewlineStyle::CrLf).unwrap(),
+            "    lorem\r\n    ipsum\r\n"
+        );
+    }
+
+    #[test]
+    fn with_mixed_and_without_final_newline() {
+        let cursor = Cursor::new("lorem\r\nipsum\ndolor");
+        assert_eq!(
+            cursor.read_lines_into_string_with_normalized_endings(NewlineStyle::Lf).unwrap(),
+            "lorem\nipsum\ndolor"
+        );
+    }
+
+}
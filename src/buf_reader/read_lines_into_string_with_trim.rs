@@ -1,9 +1,8 @@
-use std::fs::File;
-use std::io::BufReader;
 use std::io::BufRead;
+use crate::options::*;
 use crate::traits::*;
 
-impl ReadLinesIntoStringWithTrimOnSelf for BufReader<File> {
+impl<R: BufRead> ReadLinesIntoStringWithTrimOnSelf for R {
 
     /// Read lines into String; trim each line of whitespace.
     /// 
@@ -19,14 +18,11 @@ impl ReadLinesIntoStringWithTrimOnSelf for BufReader<File> {
     /// 
     /// Any error will return immediately.
     /// 
-    fn read_lines_into_string_with_trim(self) -> ::std::io::Result<String> {
-        let mut string = String::new();
-        let lines = self.lines();
-        for line in lines {
-            let x = line?;
-            string.push_str(&x.trim());
-        }
-        Ok(string)
+    fn read_lines_into_string_with_trim(self) -> crate::Result<String> {
+        ReadLinesOptions::new()
+            .endings(Endings::Clip)
+            .whitespace(Whitespace::Trim)
+            .read_into(self)
     }
 
 }
@@ -34,6 +30,9 @@ impl ReadLinesIntoStringWithTrimOnSelf for BufReader<File> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
 
     fn sut(path_str: &str) -> BufReader<File> {
         BufReader::new(File::open(path_str).unwrap())
@@ -66,4 +65,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn with_cursor() {
+        let cursor = Cursor::new(" lorem \n\tipsum\r\ndolor ");
+        assert_eq!(
+            cursor.read_lines_into_string_with_trim().unwrap(),
+            "loremipsumdolor"
+        );
+    }
+
+    #[test]
+    fn with_mut_ref() {
+        let mut buf_reader = sut("example.txt");
+        assert!(!(&mut buf_reader).read_lines_into_string_with_trim().unwrap().is_empty());
+        assert!(buf_reader.read_lines_into_string_with_trim().unwrap().is_empty());
+    }
+
 }
@@ -0,0 +1,74 @@
+use std::io::BufRead;
+use crate::options::*;
+use crate::traits::*;
+
+impl<R: BufRead> ReadLinesIntoVecBytesWithClipOnSelf for R {
+
+    /// Read lines into Vec<Vec<u8>>, without UTF-8 validation; clip each line end `\n` or `\r\n`.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let mut buf_reader = BufReader::new(file);
+    /// let lines: Vec<Vec<u8>> = buf_reader.read_lines_into_vec_bytes_with_clip().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_vec_bytes_with_clip(self) -> crate::Result<Vec<Vec<u8>>> {
+        ReadLinesOptions::new().endings(Endings::Clip).read_bytes_into(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
+
+    fn sut(path_str: &str) -> BufReader<File> {
+        BufReader::new(File::open(path_str).unwrap())
+    }
+
+    #[test]
+    fn with_lf() {
+        let buf_reader = sut("example.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_vec_bytes_with_clip().unwrap(),
+            vec![b"lorem".to_vec(), b"ipsum".to_vec()]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let buf_reader = sut("example-with-crlf.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_vec_bytes_with_clip().unwrap(),
+            vec![b"lorem".to_vec(), b"ipsum".to_vec()]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let buf_reader = sut("example-with-indent.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_vec_bytes_with_clip().unwrap(),
+            vec![b"    lorem".to_vec(), b"    ipsum".to_vec()]
+        );
+    }
+
+    #[test]
+    fn with_cursor() {
+        let cursor = Cursor::new(b" caf\xe9\t\r\n\x0Bipsum ");
+        assert_eq!(
+            cursor.read_lines_into_vec_bytes_with_clip().unwrap(),
+            vec![b" caf\xe9\t".to_vec(), b"\x0Bipsum ".to_vec()]
+        );
+    }
+
+}
@@ -0,0 +1,88 @@
+use std::io::BufRead;
+use crate::line::Line;
+use crate::options::*;
+use crate::traits::*;
+
+impl<R: BufRead> ReadLinesIntoLinesOnSelf for R {
+
+    /// Read lines into Vec<Line>; each line record has its line number,
+    /// byte offset, content, and line ending.
+    /// 
+    /// For other line terminators, or limits, see `ReadLinesOptions::read_lines`.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use read_lines_into::line::Line;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let mut buf_reader = BufReader::new(file);
+    /// let lines: Vec<Line> = buf_reader.read_lines_into_vec_line().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_vec_line(self) -> crate::Result<Vec<Line>> {
+        ReadLinesOptions::new().read_lines(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line_ending::LineEnding;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
+
+    fn sut(path_str: &str) -> BufReader<File> {
+        BufReader::new(File::open(path_str).unwrap())
+    }
+
+    fn line(number: usize, byte_offset: u64, content: &str, ending: LineEnding) -> Line {
+        Line { number, byte_offset, content: String::from(content), ending }
+    }
+
+    #[test]
+    fn with_lf() {
+        let buf_reader = sut("example.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_vec_line().unwrap(),
+            vec![line(1, 0, "lorem", LineEnding::Lf), line(2, 6, "ipsum", LineEnding::Lf)]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let buf_reader = sut("example-with-crlf.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_vec_line().unwrap(),
+            vec![line(1, 0, "lorem", LineEnding::CrLf), line(2, 7, "ipsum", LineEnding::CrLf)]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let buf_reader = sut("example-with-indent.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_vec_line().unwrap(),
+            vec![line(1, 0, "    lorem", LineEnding::Lf), line(2, 10, "    ipsum", LineEnding::Lf)]
+        );
+    }
+
+    #[test]
+    fn with_cursor_without_final_newline() {
+        let cursor = Cursor::new("lorem\r\n\nipsum");
+        assert_eq!(
+            cursor.read_lines_into_vec_line().unwrap(),
+            vec![
+                line(1, 0, "lorem", LineEnding::CrLf),
+                line(2, 7, "", LineEnding::Lf),
+                line(3, 8, "ipsum", LineEnding::None),
+            ]
+        );
+    }
+
+}
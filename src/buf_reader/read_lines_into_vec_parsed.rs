@@ -0,0 +1,89 @@
+use std::io::BufRead;
+use std::str::FromStr;
+use crate::options::*;
+use crate::parse_lines_error::ParseLinesError;
+use crate::traits::*;
+
+impl<R: BufRead> ReadLinesIntoParsedOnSelf for R {
+
+    /// Read lines into Vec<T>; clip each line end `\n` or `\r\n`, trim each line of whitespace, then parse each line using `FromStr`.
+    /// 
+    /// To skip blank lines and comment lines, use `ReadLinesOptions::read_parsed`.
+    /// 
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let cursor = Cursor::new("1\n 2 \r\n3\n");
+    /// let numbers: Vec<u64> = cursor.read_lines_into_vec_parsed().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately. A parse error
+    /// has the line number, the line, and the `FromStr` error.
+    /// 
+    fn read_lines_into_vec_parsed<T: FromStr>(self) -> Result<Vec<T>, ParseLinesError<T::Err>> {
+        ReadLinesOptions::new()
+            .endings(Endings::Clip)
+            .whitespace(Whitespace::Trim)
+            .read_parsed(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
+
+    fn sut(path_str: &str) -> BufReader<File> {
+        BufReader::new(File::open(path_str).unwrap())
+    }
+
+    #[test]
+    fn with_lf() {
+        let buf_reader = sut("example.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_vec_parsed::<String>().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let buf_reader = sut("example-with-crlf.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_vec_parsed::<String>().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let buf_reader = sut("example-with-indent.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_vec_parsed::<String>().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_cursor() {
+        let cursor = Cursor::new("1\n 2 \r\n3");
+        assert_eq!(
+            cursor.read_lines_into_vec_parsed::<u64>().unwrap(),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn with_parse_error() {
+        let cursor = Cursor::new("1\n2\nlorem\n");
+        assert_eq!(
+            cursor.read_lines_into_vec_parsed::<u64>().unwrap_err().to_string(),
+            "line 3: cannot parse \"lorem\": invalid digit found in string"
+        );
+    }
+
+}
@@ -1,9 +1,8 @@
-use std::fs::File;
-use std::io::BufReader;
 use std::io::BufRead;
+use crate::options::*;
 use crate::traits::*;
 
-impl ReadLinesIntoStringsOnSelf for BufReader<File> {
+impl<R: BufRead> ReadLinesIntoStringsOnSelf for R {
 
     /// Read lines into Vec<String>; keep each line end `\n` or `\r\n`.
     /// 
@@ -19,17 +18,8 @@ impl ReadLinesIntoStringsOnSelf for BufReader<File> {
     /// 
     /// Any error will return immediately.
     /// 
-    fn read_lines_into_vec_string(mut self) -> ::std::io::Result<Vec<String>> {
-        let mut strings = Vec::<String>::new();
-        let mut buf = String::new();
-        let mut n: usize;
-        loop {
-            n = self.read_line(&mut buf)?;
-            if n == 0 { break; }
-            strings.push(buf.clone());
-            buf.clear();
-        }
-        Ok(strings)
+    fn read_lines_into_vec_string(self) -> crate::Result<Vec<String>> {
+        ReadLinesOptions::new().read_into(self)
     }
 
 }
@@ -37,6 +27,9 @@ impl ReadLinesIntoStringsOnSelf for BufReader<File> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
 
     fn sut(path_str: &str) -> BufReader<File> {
         BufReader::new(File::open(path_str).unwrap())
@@ -69,4 +62,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn with_cursor() {
+        let cursor = Cursor::new("lorem\nipsum\r\ndolor");
+        assert_eq!(
+            cursor.read_lines_into_vec_string().unwrap(),
+            vec![String::from("lorem\n"), String::from("ipsum\r\n"), String::from("dolor")]
+        );
+    }
+
+    #[test]
+    fn with_mut_ref() {
+        let mut buf_reader = sut("example.txt");
+        assert!(!(&mut buf_reader).read_lines_into_vec_string().unwrap().is_empty());
+        assert!(buf_reader.read_lines_into_vec_string().unwrap().is_empty());
+    }
+
 }
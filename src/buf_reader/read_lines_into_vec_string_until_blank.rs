@@ -0,0 +1,107 @@
+use std::io::BufRead;
+use crate::options::*;
+use crate::traits::*;
+
+impl<R: BufRead> ReadLinesIntoStringsUntilBlankOnMutSelf for R {
+
+    /// Read lines into Vec<String> until a blank line; keep each line end `\n` or `\r\n`.
+    /// 
+    /// A blank line is a line that is empty apart from its line end.
+    /// The blank line is consumed, but is not included in the result.
+    /// 
+    /// The reader is borrowed, not consumed, and is positioned
+    /// right after the blank line, so it can be used again.
+    /// 
+    /// For other line terminators, or limits, see `ReadLinesOptions::until_blank`.
+    /// 
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let mut cursor = Cursor::new("Host: example.com\r\n\r\nbody\r\n");
+    /// let head: Vec<String> = cursor.read_lines_into_vec_string_until_blank().unwrap();
+    /// let body: Vec<String> = cursor.read_lines_into_vec_string().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_vec_string_until_blank(&mut self) -> crate::Result<Vec<String>> {
+        ReadLinesOptions::new().until_blank(true).read_into(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
+
+    fn sut(path_str: &str) -> BufReader<File> {
+        BufReader::new(File::open(path_str).unwrap())
+    }
+
+    #[test]
+    fn with_lf() {
+        let mut buf_reader = sut("example.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_vec_string_until_blank().unwrap(),
+            vec![String::from("lorem\n"), String::from("ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let mut buf_reader = sut("example-with-crlf.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_vec_string_until_blank().unwrap(),
+            vec![String::from("lorem\r\n"), String::from("ipsum\r\n")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let mut buf_reader = sut("example-with-indent.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_vec_string_until_blank().unwrap(),
+            vec![String::from("    lorem\n"), String::from("    ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_blank_line() {
+        let mut cursor = Cursor::new("lorem\r\n\r\nipsum\n\ndolor\n");
+        assert_eq!(
+            cursor.read_lines_into_vec_string_until_blank().unwrap(),
+            vec![String::from("lorem\r\n")]
+        );
+        assert_eq!(
+            cursor.read_lines_into_vec_string_until_blank().unwrap(),
+            vec![String::from("ipsum\n")]
+        );
+        assert_eq!(
+            cursor.read_lines_into_vec_string().unwrap(),
+            vec![String::from("dolor\n")]
+        );
+    }
+
+    #[test]
+    fn with_whitespace_line() {
+        let mut cursor = Cursor::new("lorem\n \nipsum\n");
+        assert_eq!(
+            cursor.read_lines_into_vec_string_until_blank().unwrap(),
+            vec![String::from("lorem\n"), String::from(" \n"), String::from("ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_invalid_utf8() {
+        let mut cursor = Cursor::new(b"lorem\nips\xffum\n\n".to_vec());
+        assert_eq!(
+            cursor.read_lines_into_vec_string_until_blank().unwrap_err().to_string(),
+            "line 2: stream did not contain valid UTF-8 at byte offset 9"
+        );
+    }
+
+}
@@ -0,0 +1,78 @@
+use std::io::BufRead;
+use crate::options::*;
+use crate::traits::*;
+
+impl<R: BufRead> ReadLinesIntoStringsWithDedentOnSelf for R {
+
+    /// Read lines into Vec<String>; remove the common leading indentation from all lines, and keep each line end `\n` or `\r\n`.
+    /// 
+    /// The common indentation is the longest run of spaces and tabs
+    /// that starts every line that isn't blank, like Python's `textwrap.dedent`.
+    /// Nested indentation is kept.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example-with-indent.txt").unwrap();
+    /// let mut buf_reader = BufReader::new(file);
+    /// let strings: Vec<String> = buf_reader.read_lines_into_vec_string_with_dedent().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_vec_string_with_dedent(self) -> crate::Result<Vec<String>> {
+        ReadLinesOptions::new().dedent(true).read_into(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
+
+    fn sut(path_str: &str) -> BufReader<File> {
+        BufReader::new(File::open(path_str).unwrap())
+    }
+
+    #[test]
+    fn with_lf() {
+        let buf_reader = sut("example.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_vec_string_with_dedent().unwrap(),
+            vec![String::from("lorem\n"), String::from("ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let buf_reader = sut("example-with-crlf.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_vec_string_with_dedent().unwrap(),
+            vec![String::from("lorem\r\n"), String::from("ipsum\r\n")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let buf_reader = sut("example-with-indent.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_vec_string_with_dedent().unwrap(),
+            vec![String::from("lorem\n"), String::from("ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_cursor() {
+        let cursor = Cursor::new("    lorem\r\n\n      ipsum\n\t\n    dolor");
+        assert_eq!(
+            cursor.read_lines_into_vec_string_with_dedent().unwrap(),
+            vec![String::from("lorem\r\n"), String::from("\n"), String::from("  ipsum\n"), String::from("\n"), String::from("dolor")]
+        );
+    }
+
+}
@@ -1,9 +1,8 @@
-use std::fs::File;
-use std::io::BufReader;
 use std::io::BufRead;
+use crate::options::*;
 use crate::traits::*;
 
-impl ReadLinesIntoStringsWithTrimOnSelf for BufReader<File> {
+impl<R: BufRead> ReadLinesIntoStringsWithTrimOnSelf for R {
 
     /// Read lines into Vec<String>; trim each line of whitespace.
     /// 
@@ -19,14 +18,11 @@ impl ReadLinesIntoStringsWithTrimOnSelf for BufReader<File> {
     /// 
     /// Any error will return immediately.
     /// 
-    fn read_lines_into_vec_string_with_trim(self) -> ::std::io::Result<Vec<String>> {
-        let mut strings = Vec::<String>::new();
-        let lines = self.lines();
-        for line in lines {
-            let x = line?;
-            strings.push(String::from(x.trim()));
-        }
-        Ok(strings)
+    fn read_lines_into_vec_string_with_trim(self) -> crate::Result<Vec<String>> {
+        ReadLinesOptions::new()
+            .endings(Endings::Clip)
+            .whitespace(Whitespace::Trim)
+            .read_into(self)
     }
 
 }
@@ -34,6 +30,9 @@ impl ReadLinesIntoStringsWithTrimOnSelf for BufReader<File> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
 
     fn sut(path_str: &str) -> BufReader<File> {
         BufReader::new(File::open(path_str).unwrap())
@@ -66,4 +65,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn with_cursor() {
+        let cursor = Cursor::new(" lorem \n\tipsum\r\ndolor ");
+        assert_eq!(
+            cursor.read_lines_into_vec_string_with_trim().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum"), String::from("dolor")]
+        );
+    }
+
+    #[test]
+    fn with_mut_ref() {
+        let mut buf_reader = sut("example.txt");
+        assert!(!(&mut buf_reader).read_lines_into_vec_string_with_trim().unwrap().is_empty());
+        assert!(buf_reader.read_lines_into_vec_string_with_trim().unwrap().is_empty());
+    }
+
 }
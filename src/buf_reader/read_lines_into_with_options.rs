@@ -0,0 +1,81 @@
+use std::io::BufRead;
+use crate::options::*;
+use crate::traits::*;
+
+impl<R: BufRead> ReadLinesIntoWithOptionsOnSelf for R {
+
+    /// Read lines into any collection of strings, such as String or Vec<String>, with options.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use read_lines_into::options::*;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let mut buf_reader = BufReader::new(file);
+    /// let options = ReadLinesOptions::new().endings(Endings::Clip);
+    /// let strings: Vec<String> = buf_reader.read_lines_into_with_options(&options).unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_with_options<C: FromIterator<String>>(self, options: &ReadLinesOptions) -> crate::Result<C> {
+        options.read_into(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::BufReader;
+    use std::io::Cursor;
+
+    fn sut(path_str: &str) -> BufReader<File> {
+        BufReader::new(File::open(path_str).unwrap())
+    }
+
+    fn options() -> ReadLinesOptions {
+        ReadLinesOptions::new().endings(Endings::Clip).whitespace(Whitespace::TrimEnd)
+    }
+
+    #[test]
+    fn with_lf() {
+        let buf_reader = sut("example.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_with_options::<Vec<String>>(&options()).unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let buf_reader = sut("example-with-crlf.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_with_options::<Vec<String>>(&options()).unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let buf_reader = sut("example-with-indent.txt");
+        assert_eq!(
+            buf_reader.read_lines_into_with_options::<Vec<String>>(&options()).unwrap(),
+            vec![String::from("    lorem"), String::from("    ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_cursor() {
+        let cursor = Cursor::new("lorem  \r\n\r\nipsum");
+        let options = options().skip_blank(true);
+        assert_eq!(
+            cursor.read_lines_into_with_options::<String>(&options).unwrap(),
+            "loremipsum"
+        );
+    }
+
+}
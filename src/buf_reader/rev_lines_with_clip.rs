@@ -0,0 +1,76 @@
+use std::io::BufRead;
+use std::io::Seek;
+use crate::iter::RevLinesWithClip;
+use crate::traits::*;
+
+impl<R: BufRead + Seek> RevLinesWithClipOnSelf for R {
+
+    /// Iterate lines in reverse, from last to first, one String at a time; clip each line end `\n` or `\r\n`.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let buf_reader = BufReader::new(file);
+    /// for line in buf_reader.rev_lines_with_clip() {
+    ///     let string: String = line.unwrap();
+    /// }
+    /// ```
+    /// 
+    /// Any error is yielded as an item.
+    /// 
+    fn rev_lines_with_clip(self) -> RevLinesWithClip<Self> {
+        RevLinesWithClip::new(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::BufReader;
+
+    fn sut(path_str: &str) -> BufReader<File> {
+        BufReader::new(File::open(path_str).unwrap())
+    }
+
+    #[test]
+    fn with_lf() {
+        let buf_reader = sut("example.txt");
+        assert_eq!(
+            buf_reader.rev_lines_with_clip().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("ipsum"), String::from("lorem")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let buf_reader = sut("example-with-crlf.txt");
+        assert_eq!(
+            buf_reader.rev_lines_with_clip().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("ipsum"), String::from("lorem")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let buf_reader = sut("example-with-indent.txt");
+        assert_eq!(
+            buf_reader.rev_lines_with_clip().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("    ipsum"), String::from("    lorem")]
+        );
+    }
+
+    #[test]
+    fn without_final_newline() {
+        let buf_reader = sut("example-without-final-newline.txt");
+        assert_eq!(
+            buf_reader.rev_lines_with_clip().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("ipsum"), String::from("lorem")]
+        );
+    }
+
+}
@@ -0,0 +1,153 @@
+//! Checkpoints, to resume reading an append-only file where a previous
+//! read stopped, such as for ingesting a log in batches.
+//!
+//! ```
+//! use read_lines_into::Checkpoint;
+//! use read_lines_into::traits::*;
+//!
+//! let (strings, checkpoint) = "example.txt".read_lines_from_offset(Checkpoint::new()).unwrap();
+//! assert_eq!(strings, vec!["lorem\n", "ipsum\n"]);
+//! assert_eq!(checkpoint.offset, 12);
+//!
+//! // Save the checkpoint as text, then later, load it and resume.
+//! let text = checkpoint.to_string();
+//! let checkpoint: Checkpoint = text.parse().unwrap();
+//! let (strings, _) = "example.txt".read_lines_from_offset(checkpoint).unwrap();
+//! assert!(strings.is_empty());
+//! ```
+
+use std::fmt;
+use std::fs::Metadata;
+use std::str::FromStr;
+use crate::error::Error;
+use crate::file_identity::file_identity;
+
+/// Where a read stopped in a file, and which file it was.
+///
+/// The text form, via `Display` and `FromStr`, is like
+/// `offset=12 dev=2049 ino=131 len=12`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Checkpoint {
+    /// The byte offset of the end of the last fully consumed line.
+    pub offset: u64,
+    /// The file's device, or 0 where unknown.
+    pub dev: u64,
+    /// The file's inode, or 0 where unknown.
+    pub ino: u64,
+    /// The file's length in bytes, when the checkpoint was made.
+    pub len: u64,
+}
+
+impl Checkpoint {
+
+    /// Create a checkpoint at the start of any file.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create a checkpoint at a byte offset in the file with this metadata.
+    pub(crate) fn of(metadata: &Metadata, offset: u64) -> Self {
+        let (dev, ino) = file_identity(metadata).unwrap_or((0, 0));
+        Self { offset, dev, ino, len: metadata.len() }
+    }
+
+    /// Check that the file with this metadata is the checkpoint's file,
+    /// and is not truncated.
+    ///
+    /// A checkpoint at offset 0 matches any file.
+    pub(crate) fn check(&self, metadata: &Metadata) -> crate::Result<()> {
+        if self.offset == 0 {
+            return Ok(());
+        }
+        let now = Self::of(metadata, self.offset);
+        if (now.dev, now.ino) != (self.dev, self.ino) || now.len < self.len {
+            return Err(Error::CheckpointMismatch { path: None, checkpoint: *self });
+        }
+        Ok(())
+    }
+
+}
+
+impl fmt::Display for Checkpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "offset={} dev={} ino={} len={}", self.offset, self.dev, self.ino, self.len)
+    }
+}
+
+impl FromStr for Checkpoint {
+    type Err = ParseCheckpointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut fields: [Option<u64>; 4] = [None; 4];
+        for pair in s.split_whitespace() {
+            let (key, value) = pair.split_once('=').ok_or_else(|| ParseCheckpointError::new(s))?;
+            let i = match key {
+                "offset" => 0,
+                "dev" => 1,
+                "ino" => 2,
+                "len" => 3,
+                _ => return Err(ParseCheckpointError::new(s)),
+            };
+            fields[i] = Some(value.parse().map_err(|_| ParseCheckpointError::new(s))?);
+        }
+        match fields {
+            [Some(offset), Some(dev), Some(ino), Some(len)] => Ok(Self { offset, dev, ino, len }),
+            _ => Err(ParseCheckpointError::new(s)),
+        }
+    }
+}
+
+/// An error parsing a checkpoint from text.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCheckpointError {
+    text: String,
+}
+
+impl ParseCheckpointError {
+    fn new(text: &str) -> Self {
+        Self { text: String::from(text) }
+    }
+}
+
+impl fmt::Display for ParseCheckpointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot parse checkpoint {:?}", self.text)
+    }
+}
+
+impl ::std::error::Error for ParseCheckpointError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_and_from_str() {
+        let checkpoint = Checkpoint { offset: 6, dev: 2049, ino: 131, len: 12 };
+        assert_eq!(checkpoint.to_string(), "offset=6 dev=2049 ino=131 len=12");
+        assert_eq!("offset=6 dev=2049 ino=131 len=12".parse(), Ok(checkpoint));
+        assert_eq!("len=12 ino=131  dev=2049 offset=6\n".parse(), Ok(checkpoint));
+    }
+
+    #[test]
+    fn from_str_errors() {
+        for text in ["", "offset=6", "offset=6 dev=2049 ino=131", "offset=x dev=2049 ino=131 len=12", "offset=6 dev=2049 ino=131 len=12 lorem=1"] {
+            assert!(text.parse::<Checkpoint>().is_err(), "{:?}", text);
+        }
+        assert_eq!("lorem".parse::<Checkpoint>().unwrap_err().to_string(), "cannot parse checkpoint \"lorem\"");
+    }
+
+    #[test]
+    fn check() {
+        let metadata = ::std::fs::metadata("example.txt").unwrap();
+        let checkpoint = Checkpoint::of(&metadata, 6);
+        assert!(checkpoint.check(&metadata).is_ok());
+        assert!(Checkpoint { len: 13, ..checkpoint }.check(&metadata).is_err());
+        assert!(Checkpoint { len: 13, offset: 0, ..checkpoint }.check(&metadata).is_ok());
+        let other = ::std::fs::metadata("example-with-crlf.txt").unwrap();
+        if cfg!(unix) {
+            assert!(checkpoint.check(&other).is_err());
+        }
+    }
+
+}
@@ -0,0 +1,306 @@
+//! A reader that strips a byte order mark, and decodes UTF-16 into UTF-8,
+//! so that every line reader can read it as UTF-8.
+//!
+//! ```
+//! use std::fs::File;
+//! use std::io::BufReader;
+//! use read_lines_into::DecodingReader;
+//! use read_lines_into::Encoding;
+//! use read_lines_into::traits::*;
+//!
+//! let file: File = File::open("example-with-utf16le.txt").unwrap();
+//! let mut reader = DecodingReader::new(BufReader::new(file));
+//! let strings = (&mut reader).read_lines_into_vec_string().unwrap();
+//! assert_eq!(strings, vec!["lorem\n", "ipsum\n"]);
+//! assert_eq!(reader.encoding(), Some(Encoding::Utf16Le));
+//! assert!(reader.has_bom());
+//! ```
+
+use std::io::BufRead;
+use std::io::Error;
+use std::io::ErrorKind;
+use std::io::Read;
+use crate::encoding::Encoding;
+
+/// A `BufRead` that strips a byte order mark, and decodes UTF-16 into UTF-8.
+#[derive(Debug)]
+pub struct DecodingReader<R> {
+    reader: R,
+    /// The encoding, if given, else detected by byte order mark.
+    given: Option<Encoding>,
+    /// The encoding, once known.
+    encoding: Option<Encoding>,
+    bom: bool,
+    /// Bytes to serve before any more bytes from the reader.
+    out: Vec<u8>,
+    pos: usize,
+    /// UTF-16 bytes that don't yet make a whole character.
+    pending: Vec<u8>,
+}
+
+impl<R: BufRead> DecodingReader<R> {
+
+    /// Create a reader that detects the encoding by byte order mark,
+    /// else uses UTF-8.
+    pub fn new(reader: R) -> Self {
+        Self {
+            reader,
+            given: None,
+            encoding: None,
+            bom: false,
+            out: Vec::new(),
+            pos: 0,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Create a reader that uses the given encoding.
+    ///
+    /// A byte order mark for the given encoding is stripped;
+    /// a byte order mark for any other encoding is an error.
+    pub fn with_encoding(reader: R, encoding: Encoding) -> Self {
+        Self { given: Some(encoding), ..Self::new(reader) }
+    }
+
+    /// Create a reader that passes bytes through as is, with no detection.
+    pub(crate) fn pass_through(reader: R) -> Self {
+        Self { encoding: Some(Encoding::Utf8), ..Self::new(reader) }
+    }
+
+    /// Get the encoding, or `None` until the first read.
+    pub fn encoding(&self) -> Option<Encoding> {
+        self.encoding
+    }
+
+    /// Is there a byte order mark? This is false until the first read.
+    pub fn has_bom(&self) -> bool {
+        self.bom
+    }
+
+    /// Get the underlying reader back.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Read the first bytes, to detect and strip any byte order mark.
+    fn detect(&mut self) -> ::std::io::Result<Encoding> {
+        let mut head = Vec::<u8>::new();
+        while head.len() < 3 {
+            let available = match self.reader.fill_buf() {
+                Ok(x) => x,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            if available.is_empty() {
+                break;
+            }
+            let n = available.len().min(3 - head.len());
+            head.extend_from_slice(&available[..n]);
+            self.reader.consume(n);
+        }
+        let found = Encoding::from_bom(&head);
+        let encoding = match (self.given, found) {
+            (Some(given), Some(found)) if given != found => {
+                return Err(Error::new(ErrorKind::InvalidData, format!(
+                    "expected {}, but found a {} byte order mark",
+                    given.name(),
+                    found.name(),
+                )));
+            }
+            (Some(given), _) => given,
+            (None, found) => found.unwrap_or(Encoding::Utf8),
+        };
+        self.bom = found.is_some();
+        let rest = &head[found.map_or(0, |x| x.bom().len())..];
+        match encoding {
+            Encoding::Utf8 => self.out.extend_from_slice(rest),
+            Encoding::Utf16Le | Encoding::Utf16Be => self.pending.extend_from_slice(rest),
+        }
+        self.encoding = Some(encoding);
+        Ok(encoding)
+    }
+
+    /// Decode more UTF-16 bytes from the reader into `out`,
+    /// until there is at least one character, or the end.
+    fn decode_utf16(&mut self, encoding: Encoding) -> ::std::io::Result<()> {
+        self.out.clear();
+        self.pos = 0;
+        while self.out.is_empty() {
+            let available = match self.reader.fill_buf() {
+                Ok(x) => x,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            if available.is_empty() {
+                if self.pending.is_empty() {
+                    return Ok(());
+                }
+                return Err(invalid_utf16());
+            }
+            let mut bytes = ::std::mem::take(&mut self.pending);
+            bytes.extend_from_slice(available);
+            let n = available.len();
+            self.reader.consume(n);
+            let mut units: Vec<u16> = bytes.chunks_exact(2).map(|x| match encoding {
+                Encoding::Utf16Be => u16::from_be_bytes([x[0], x[1]]),
+                _ => u16::from_le_bytes([x[0], x[1]]),
+            }).collect();
+            let mut keep = bytes.len() % 2;
+            if units.last().is_some_and(|x| (0xD800..0xDC00).contains(x)) {
+                units.pop();
+                keep += 2;
+            }
+            self.pending.extend_from_slice(&bytes[bytes.len() - keep..]);
+            for c in char::decode_utf16(units) {
+                let c = c.map_err(|_| invalid_utf16())?;
+                self.out.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+            }
+        }
+        Ok(())
+    }
+
+}
+
+fn invalid_utf16() -> Error {
+    Error::new(ErrorKind::InvalidData, "stream did not contain valid UTF-16")
+}
+
+impl<R: BufRead> BufRead for DecodingReader<R> {
+
+    fn fill_buf(&mut self) -> ::std::io::Result<&[u8]> {
+        let encoding = match self.encoding {
+            Some(encoding) => encoding,
+            None => self.detect()?,
+        };
+        if self.pos < self.out.len() {
+            return Ok(&self.out[self.pos..]);
+        }
+        match encoding {
+            Encoding::Utf8 => self.reader.fill_buf(),
+            Encoding::Utf16Le | Encoding::Utf16Be => {
+                self.decode_utf16(encoding)?;
+                Ok(&self.out[self.pos..])
+            }
+        }
+    }
+
+    fn consume(&mut self, amt: usize) {
+        if self.pos < self.out.len() {
+            self.pos += amt;
+        } else {
+            self.reader.consume(amt);
+        }
+    }
+
+}
+
+impl<R: BufRead> Read for DecodingReader<R> {
+
+    fn read(&mut self, buf: &mut [u8]) -> ::std::io::Result<usize> {
+        let available = self.fill_buf()?;
+        let n = available.len().min(buf.len());
+        buf[..n].copy_from_slice(&available[..n]);
+        self.consume(n);
+        Ok(n)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    /// A reader that gives one byte at a time, to test buffer boundaries.
+    fn one_byte_at_a_time(bytes: &[u8]) -> ::std::io::BufReader<::std::io::Chain<Cursor<Vec<u8>>, ::std::io::Empty>> {
+        ::std::io::BufReader::with_capacity(1, Cursor::new(bytes.to_vec()).chain(::std::io::empty()))
+    }
+
+    fn utf16(text: &str, encoding: Encoding, bom: bool) -> Vec<u8> {
+        let mut bytes = if bom { encoding.bom().to_vec() } else { Vec::new() };
+        for unit in text.encode_utf16() {
+            match encoding {
+                Encoding::Utf16Be => bytes.extend_from_slice(&unit.to_be_bytes()),
+                _ => bytes.extend_from_slice(&unit.to_le_bytes()),
+            }
+        }
+        bytes
+    }
+
+    fn read_all(mut reader: impl Read) -> ::std::io::Result<String> {
+        let mut string = String::new();
+        reader.read_to_string(&mut string)?;
+        Ok(string)
+    }
+
+    const TEXT: &str = "lorem\r\nipsum 😀\ndolor";
+
+    #[test]
+    fn utf8_without_bom() {
+        let mut reader = DecodingReader::new(Cursor::new(TEXT));
+        assert_eq!(read_all(&mut reader).unwrap(), TEXT);
+        assert_eq!(reader.encoding(), Some(Encoding::Utf8));
+        assert!(!reader.has_bom());
+    }
+
+    #[test]
+    fn utf8_with_bom() {
+        let bytes = [Encoding::Utf8.bom(), TEXT.as_bytes()].concat();
+        let mut reader = DecodingReader::new(Cursor::new(bytes));
+        assert_eq!(read_all(&mut reader).unwrap(), TEXT);
+        assert_eq!(reader.encoding(), Some(Encoding::Utf8));
+        assert!(reader.has_bom());
+    }
+
+    #[test]
+    fn utf16_with_bom() {
+        for encoding in [Encoding::Utf16Le, Encoding::Utf16Be] {
+            let bytes = utf16(TEXT, encoding, true);
+            let mut reader = DecodingReader::new(Cursor::new(bytes.clone()));
+            assert_eq!(read_all(&mut reader).unwrap(), TEXT);
+            assert_eq!(reader.encoding(), Some(encoding));
+            assert!(reader.has_bom());
+            assert_eq!(read_all(DecodingReader::new(one_byte_at_a_time(&bytes))).unwrap(), TEXT);
+        }
+    }
+
+    #[test]
+    fn utf16_given() {
+        for encoding in [Encoding::Utf16Le, Encoding::Utf16Be] {
+            let bytes = utf16(TEXT, encoding, false);
+            let mut reader = DecodingReader::with_encoding(Cursor::new(bytes), encoding);
+            assert_eq!(read_all(&mut reader).unwrap(), TEXT);
+            assert!(!reader.has_bom());
+        }
+    }
+
+    #[test]
+    fn short_and_empty() {
+        assert_eq!(read_all(DecodingReader::new(Cursor::new("a"))).unwrap(), "a");
+        assert_eq!(read_all(DecodingReader::new(Cursor::new(""))).unwrap(), "");
+        assert_eq!(read_all(DecodingReader::new(Cursor::new(b"\xFF\xFE"))).unwrap(), "");
+        assert_eq!(read_all(DecodingReader::new(one_byte_at_a_time(b"\xEF\xBB\xBFa"))).unwrap(), "a");
+    }
+
+    #[test]
+    fn invalid_utf16() {
+        let odd = [utf16("lorem", Encoding::Utf16Le, true), vec![b'x']].concat();
+        assert!(read_all(DecodingReader::new(Cursor::new(odd))).is_err());
+        let unpaired = [Encoding::Utf16Le.bom(), b"\x00\xD8a\x00"].concat();
+        assert!(read_all(DecodingReader::new(Cursor::new(unpaired))).is_err());
+    }
+
+    #[test]
+    fn given_encoding_with_other_bom() {
+        let bytes = utf16(TEXT, Encoding::Utf16Be, true);
+        let e = read_all(DecodingReader::with_encoding(Cursor::new(bytes), Encoding::Utf16Le)).unwrap_err();
+        assert_eq!(e.to_string(), "expected UTF-16LE, but found a UTF-16BE byte order mark");
+    }
+
+    #[test]
+    fn pass_through_keeps_bom() {
+        let bytes = [Encoding::Utf8.bom(), b"a"].concat();
+        assert_eq!(read_all(DecodingReader::pass_through(Cursor::new(bytes))).unwrap(), "\u{FEFF}a");
+    }
+
+}
@@ -0,0 +1,81 @@
+//! Text encodings, such as UTF-8 and UTF-16.
+//!
+//! To decode a source, and to know which encoding it has, see `DecodingReader`.
+
+/// A text encoding.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Encoding {
+    /// UTF-8, with or without a byte order mark `EF BB BF`.
+    Utf8,
+    /// UTF-16 little-endian, with or without a byte order mark `FF FE`.
+    Utf16Le,
+    /// UTF-16 big-endian, with or without a byte order mark `FE FF`.
+    Utf16Be,
+}
+
+impl Encoding {
+
+    /// Get the encoding's byte order mark.
+    ///
+    /// ```
+    /// use read_lines_into::Encoding;
+    ///
+    /// assert_eq!(Encoding::Utf8.bom(), b"\xEF\xBB\xBF");
+    /// assert_eq!(Encoding::Utf16Le.bom(), b"\xFF\xFE");
+    /// ```
+    ///
+    pub fn bom(&self) -> &'static [u8] {
+        match self {
+            Encoding::Utf8 => b"\xEF\xBB\xBF",
+            Encoding::Utf16Le => b"\xFF\xFE",
+            Encoding::Utf16Be => b"\xFE\xFF",
+        }
+    }
+
+    /// Detect an encoding by its byte order mark at the start of some bytes.
+    ///
+    /// ```
+    /// use read_lines_into::Encoding;
+    ///
+    /// assert_eq!(Encoding::from_bom(b"\xFE\xFF\x00l"), Some(Encoding::Utf16Be));
+    /// assert_eq!(Encoding::from_bom(b"lorem"), None);
+    /// ```
+    ///
+    pub fn from_bom(bytes: &[u8]) -> Option<Encoding> {
+        [Encoding::Utf8, Encoding::Utf16Le, Encoding::Utf16Be]
+            .into_iter()
+            .find(|encoding| bytes.starts_with(encoding.bom()))
+    }
+
+    /// Get the encoding's name, such as for messages.
+    ///
+    /// ```
+    /// use read_lines_into::Encoding;
+    ///
+    /// assert_eq!(Encoding::Utf16Le.name(), "UTF-16LE");
+    /// ```
+    ///
+    pub fn name(&self) -> &'static str {
+        match self {
+            Encoding::Utf8 => "UTF-8",
+            Encoding::Utf16Le => "UTF-16LE",
+            Encoding::Utf16Be => "UTF-16BE",
+        }
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_bom() {
+        assert_eq!(Encoding::from_bom(b"\xEF\xBB\xBFlorem"), Some(Encoding::Utf8));
+        assert_eq!(Encoding::from_bom(b"\xFF\xFEl\x00"), Some(Encoding::Utf16Le));
+        assert_eq!(Encoding::from_bom(b"\xFE\xFF\x00l"), Some(Encoding::Utf16Be));
+        assert_eq!(Encoding::from_bom(b"\xEF\xBB"), None);
+        assert_eq!(Encoding::from_bom(b""), None);
+    }
+
+}
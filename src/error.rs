@@ -0,0 +1,295 @@
+//! Errors with context, such as the path, line number, and byte offset.
+//!
+//! ```
+//! use read_lines_into::Error;
+//! use read_lines_into::traits::*;
+//!
+//! match "missing.txt".read_lines_into_string() {
+//!     Err(Error::Open { path, .. }) => assert_eq!(path.to_str(), Some("missing.txt")),
+//!     _ => unreachable!(),
+//! }
+//! ```
+//!
+//! An `Error` converts to and from `std::io::Error`, so you can use `?`
+//! in a function that returns `std::io::Result`:
+//!
+//! ```
+//! use read_lines_into::traits::*;
+//!
+//! fn count() -> std::io::Result<usize> {
+//!     Ok("example.txt".read_lines_into_vec_string()?.len())
+//! }
+//! ```
+
+use std::fmt;
+use std::io::ErrorKind;
+use std::path::Path;
+use std::path::PathBuf;
+use crate::checkpoint::Checkpoint;
+use crate::line_ending::LineEnding;
+
+/// A result with an `Error`.
+pub type Result<T> = ::std::result::Result<T, Error>;
+
+/// An error reading lines.
+#[derive(Debug)]
+pub enum Error {
+    /// Opening a file failed.
+    Open {
+        /// The path of the file.
+        path: PathBuf,
+        /// The error from opening.
+        source: ::std::io::Error,
+    },
+    /// Reading failed.
+    Read {
+        /// The path of the file, if known.
+        path: Option<PathBuf>,
+        /// The line number of the line being read, if known, starting at 1.
+        line: Option<usize>,
+        /// The error from reading.
+        source: ::std::io::Error,
+    },
+    /// A line is not valid UTF-8.
+    InvalidUtf8 {
+        /// The path of the file, if known.
+        path: Option<PathBuf>,
+        /// The line number, if known, starting at 1.
+        line: Option<usize>,
+        /// The byte offset of the first invalid byte, from the start of the source.
+        /// With `ReadLinesOptions::decode`, this counts the decoded UTF-8
+        /// bytes after any byte order mark, rather than the source bytes.
+        byte_offset: u64,
+    },
+    /// A line ends differently from the dominant line ending,
+    /// as found by `ReadLinesOptions::strict_endings`.
+    MixedEndings {
+        /// The path of the file, if known.
+        path: Option<PathBuf>,
+        /// The line number, starting at 1.
+        line: usize,
+        /// The line's line ending.
+        found: LineEnding,
+        /// The dominant line ending.
+        expected: LineEnding,
+    },
+    /// A resource limit was exceeded.
+    LimitExceeded {
+        /// The path of the file, if known.
+        path: Option<PathBuf>,
+        /// The line number of the line being read, if known, starting at 1.
+        line: Option<usize>,
+        /// The limit.
+        limit: Limit,
+    },
+    /// A checkpoint doesn't match the file, because the file was replaced or truncated.
+    CheckpointMismatch {
+        /// The path of the file, if known.
+        path: Option<PathBuf>,
+        /// The checkpoint.
+        checkpoint: Checkpoint,
+    },
+    /// Parsing a line failed.
+    Parse {
+        /// The path of the file, if known.
+        path: Option<PathBuf>,
+        /// The line number, starting at 1.
+        line: usize,
+        /// The line content, as given to the parser.
+        content: String,
+        /// The error from the parser.
+        source: Box<dyn ::std::error::Error + Send + Sync>,
+    },
+}
+
+/// A resource limit, as set by `Limits`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Limit {
+    /// The maximum number of bytes in one line, not including the line ending.
+    LineBytes(usize),
+    /// The maximum number of lines.
+    Lines(usize),
+    /// The maximum number of bytes in all lines.
+    TotalBytes(u64),
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Limit::LineBytes(max) => write!(f, "line is longer than {} bytes", max),
+            Limit::Lines(max) => write!(f, "more than {} lines", max),
+            Limit::TotalBytes(max) => write!(f, "more than {} bytes", max),
+        }
+    }
+}
+
+impl Error {
+
+    /// Create an error for opening a file.
+    pub fn open(path: impl AsRef<Path>, source: ::std::io::Error) -> Self {
+        Error::Open { path: path.as_ref().to_path_buf(), source }
+    }
+
+    /// Set the path of the file, if the error doesn't have one yet.
+    pub fn with_path(mut self, path: impl AsRef<Path>) -> Self {
+        match &mut self {
+            Error::Open { .. } => {}
+            Error::Read { path: x, .. }
+            | Error::InvalidUtf8 { path: x, .. }
+            | Error::MixedEndings { path: x, .. }
+            | Error::LimitExceeded { path: x, .. }
+            | Error::CheckpointMismatch { path: x, .. }
+            | Error::Parse { path: x, .. } => {
+                if x.is_none() {
+                    *x = Some(path.as_ref().to_path_buf());
+                }
+            }
+        }
+        self
+    }
+
+    /// Get the kind of the equivalent `std::io::Error`.
+    pub fn kind(&self) -> ErrorKind {
+        match self {
+            Error::Open { source, .. } | Error::Read { source, .. } => source.kind(),
+            Error::InvalidUtf8 { .. }
+            | Error::MixedEndings { .. }
+            | Error::LimitExceeded { .. }
+            | Error::CheckpointMismatch { .. }
+            | Error::Parse { .. } => ErrorKind::InvalidData,
+        }
+    }
+
+}
+
+/// Write a location, such as "example.txt:2: " or "line 2: ".
+fn write_location(f: &mut fmt::Formatter<'_>, path: &Option<PathBuf>, line: Option<usize>) -> fmt::Result {
+    match (path, line) {
+        (Some(path), Some(line)) => write!(f, "{}:{}: ", path.display(), line),
+        (Some(path), None) => write!(f, "{}: ", path.display()),
+        (None, Some(line)) => write!(f, "line {}: ", line),
+        (None, None) => Ok(()),
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Open { path, source } => {
+                write!(f, "cannot open {}: {}", path.display(), source)
+            }
+            Error::Read { path, line, source } => {
+                write_location(f, path, *line)?;
+                write!(f, "{}", source)
+            }
+            Error::InvalidUtf8 { path, line, byte_offset } => {
+                write_location(f, path, *line)?;
+                write!(f, "stream did not contain valid UTF-8 at byte offset {}", byte_offset)
+            }
+            Error::MixedEndings { path, line, found, expected } => {
+                write_location(f, path, Some(*line))?;
+                write!(f, "line ends with {}, but most lines end with {}", found.name(), expected.name())
+            }
+            Error::LimitExceeded { path, line, limit } => {
+                write_location(f, path, *line)?;
+                write!(f, "{}", limit)
+            }
+            Error::CheckpointMismatch { path, checkpoint } => {
+                write_location(f, path, None)?;
+                write!(f, "file was replaced or truncated since checkpoint {}", checkpoint)
+            }
+            Error::Parse { path, line, content, source } => {
+                write_location(f, path, Some(*line))?;
+                write!(f, "cannot parse {:?}: {}", content, source)
+            }
+        }
+    }
+}
+
+impl ::std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+        match self {
+            Error::Open { source, .. } | Error::Read { source, .. } => Some(source),
+            Error::Parse { source, .. } => Some(source.as_ref()),
+            Error::InvalidUtf8 { .. }
+            | Error::MixedEndings { .. }
+            | Error::LimitExceeded { .. }
+            | Error::CheckpointMismatch { .. } => None,
+        }
+    }
+}
+
+/// Convert an `std::io::Error` into a read error, or,
+/// if it wraps an `Error`, then unwrap that `Error`.
+impl From<::std::io::Error> for Error {
+    fn from(e: ::std::io::Error) -> Self {
+        if e.get_ref().is_some_and(|x| x.is::<Error>()) {
+            return *e.into_inner().unwrap().downcast::<Error>().unwrap();
+        }
+        Error::Read { path: None, line: None, source: e }
+    }
+}
+
+/// Convert an `Error` into an `std::io::Error` of the same kind,
+/// which wraps the `Error`, so it can be unwrapped again.
+impl From<Error> for ::std::io::Error {
+    fn from(e: Error) -> Self {
+        ::std::io::Error::new(e.kind(), e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as _;
+
+    fn invalid_utf8() -> Error {
+        Error::InvalidUtf8 { path: None, line: Some(2), byte_offset: 9 }
+    }
+
+    #[test]
+    fn display() {
+        let e = Error::open("missing.txt", ::std::io::Error::new(ErrorKind::NotFound, "lorem"));
+        assert_eq!(e.to_string(), "cannot open missing.txt: lorem");
+        assert_eq!(invalid_utf8().to_string(), "line 2: stream did not contain valid UTF-8 at byte offset 9");
+        assert_eq!(invalid_utf8().with_path("example.txt").to_string(), "example.txt:2: stream did not contain valid UTF-8 at byte offset 9");
+        let e = Error::Read { path: None, line: None, source: ::std::io::Error::other("lorem") };
+        assert_eq!(e.to_string(), "lorem");
+        assert_eq!(e.with_path("example.txt").to_string(), "example.txt: lorem");
+        let e = Error::LimitExceeded { path: None, line: Some(3), limit: Limit::LineBytes(80) };
+        assert_eq!(e.to_string(), "line 3: line is longer than 80 bytes");
+        let e = Error::MixedEndings { path: None, line: 2, found: LineEnding::CrLf, expected: LineEnding::Lf };
+        assert_eq!(e.with_path("example.txt").to_string(), "example.txt:2: line ends with CRLF, but most lines end with LF");
+    }
+
+    #[test]
+    fn with_path_keeps_first_path() {
+        let e = invalid_utf8().with_path("lorem.txt").with_path("ipsum.txt");
+        assert_eq!(e.to_string(), "lorem.txt:2: stream did not contain valid UTF-8 at byte offset 9");
+    }
+
+    #[test]
+    fn kind_and_source() {
+        let e = Error::open("missing.txt", ::std::io::Error::new(ErrorKind::NotFound, "lorem"));
+        assert_eq!(e.kind(), ErrorKind::NotFound);
+        assert_eq!(e.source().unwrap().to_string(), "lorem");
+        assert_eq!(invalid_utf8().kind(), ErrorKind::InvalidData);
+        assert!(invalid_utf8().source().is_none());
+    }
+
+    #[test]
+    fn to_and_from_io_error() {
+        let io_error: ::std::io::Error = invalid_utf8().into();
+        assert_eq!(io_error.kind(), ErrorKind::InvalidData);
+        assert_eq!(io_error.to_string(), invalid_utf8().to_string());
+        match Error::from(io_error) {
+            Error::InvalidUtf8 { line, byte_offset, .. } => assert_eq!((line, byte_offset), (Some(2), 9)),
+            e => panic!("{:?}", e),
+        }
+        match Error::from(::std::io::Error::new(ErrorKind::NotFound, "lorem")) {
+            Error::Read { path: None, line: None, source } => assert_eq!(source.kind(), ErrorKind::NotFound),
+            e => panic!("{:?}", e),
+        }
+    }
+
+}
@@ -0,0 +1,68 @@
+use std::fs::File;
+use std::io::BufReader;
+use crate::iter::LinesWithEndings;
+use crate::traits::*;
+
+impl LinesWithEndingsOnRefSelf for File {
+
+    /// Iterate lines lazily, one String at a time; keep each line end `\n` or `\r\n`.
+    /// 
+    /// The iterator reads from a clone of the file handle,
+    /// which shares the file's read position.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// for line in file.lines_with_endings().unwrap() {
+    ///     let string: String = line.unwrap();
+    /// }
+    /// ```
+    /// 
+    /// Any error cloning the file handle will return immediately;
+    /// any error reading is yielded as an item.
+    /// 
+    fn lines_with_endings(&self) -> crate::Result<LinesWithEndings<BufReader<File>>> {
+        let buf_reader = BufReader::new(self.try_clone()?);
+        Ok(buf_reader.lines_with_endings())
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sut(path_str: &str) -> File {
+        File::open(path_str).unwrap()
+    }
+
+    #[test]
+    fn with_lf() {
+        let file = sut("example.txt");
+        assert_eq!(
+            file.lines_with_endings().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("lorem\n"), String::from("ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let file = sut("example-with-crlf.txt");
+        assert_eq!(
+            file.lines_with_endings().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("lorem\r\n"), String::from("ipsum\r\n")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let file = sut("example-with-indent.txt");
+        assert_eq!(
+            file.lines_with_endings().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("    lorem\n"), String::from("    ipsum\n")]
+        );
+    }
+
+}
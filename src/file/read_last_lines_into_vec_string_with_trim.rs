@@ -0,0 +1,64 @@
+use std::fs::File;
+use std::io::BufReader;
+use crate::traits::*;
+
+impl ReadLastLinesIntoStringsWithTrimOnRefSelf for File {
+
+    /// Read the last n lines into Vec<String>; trim each line.
+    /// 
+    /// This seeks backwards from the end, so it reads about as many
+    /// bytes as the last `n` lines, rather than the whole source.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let strings: Vec<String> = file.read_last_lines_into_vec_string_with_trim(200).unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_last_lines_into_vec_string_with_trim(&self, n: usize) -> crate::Result<Vec<String>> {
+        let buf_reader = BufReader::new(self);
+        buf_reader.read_last_lines_into_vec_string_with_trim(n)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sut(path_str: &str) -> File {
+        File::open(path_str).unwrap()
+    }
+
+    #[test]
+    fn with_lf() {
+        let file = sut("example.txt");
+        assert_eq!(
+            file.read_last_lines_into_vec_string_with_trim(1).unwrap(),
+            vec![String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let file = sut("example-with-crlf.txt");
+        assert_eq!(
+            file.read_last_lines_into_vec_string_with_trim(1).unwrap(),
+            vec![String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let file = sut("example-with-indent.txt");
+        assert_eq!(
+            file.read_last_lines_into_vec_string_with_trim(1).unwrap(),
+            vec![String::from("ipsum")]
+        );
+    }
+
+}
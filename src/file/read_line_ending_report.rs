@@ -0,0 +1,61 @@
+use std::fs::File;
+use std::io::BufReader;
+use crate::line_ending_report::LineEndingReport;
+use crate::traits::*;
+
+impl ReadLineEndingReportOnRefSelf for File {
+
+    /// Read lines into a LineEndingReport; count each line end `\n`, `\r\n`, or `\r`.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use read_lines_into::line_ending_report::LineEndingReport;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let report: LineEndingReport = file.read_line_ending_report().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_line_ending_report(&self) -> crate::Result<LineEndingReport> {
+        let buf_reader = BufReader::new(self);
+        buf_reader.read_line_ending_report()
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line_ending::LineEnding;
+
+    fn sut(path_str: &str) -> File {
+        File::open(path_str).unwrap()
+    }
+
+    #[test]
+    fn with_lf() {
+        let file = sut("example.txt");
+        assert_eq!(file.read_line_ending_report().unwrap().dominant, Some(LineEnding::Lf));
+    }
+
+    #[test]
+    fn with_crlf() {
+        let file = sut("example-with-crlf.txt");
+        assert_eq!(file.read_line_ending_report().unwrap().dominant, Some(LineEnding::CrLf));
+    }
+
+    #[test]
+    fn with_indent() {
+        let file = sut("example-with-indent.txt");
+        assert_eq!(file.read_line_ending_report().unwrap().dominant, Some(LineEnding::Lf));
+    }
+
+    #[test]
+    fn with_mixed_endings() {
+        let file = sut("example-with-mixed-endings.txt");
+        assert!(file.read_line_ending_report().unwrap().is_mixed);
+    }
+
+}
@@ -0,0 +1,74 @@
+use std::fs::File;
+use std::io::BufReader;
+use crate::traits::*;
+
+impl ReadLinesExtendOnRefSelf for File {
+
+    /// Read lines and append them to an existing collection of strings,
+    /// such as Vec<String> or HashSet<String>; clip each line end, as in `read_lines_into`.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let mut strings: Vec<String> = vec![String::from("header")];
+    /// let file: File = File::open("example.txt").unwrap();
+    /// file.read_lines_extend(&mut strings).unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately, and the lines
+    /// before the error will already be in the collection.
+    /// 
+    fn read_lines_extend(&self, collection: &mut impl Extend<String>) -> crate::Result<()> {
+        let buf_reader = BufReader::new(self);
+        buf_reader.read_lines_extend(collection)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sut(path_str: &str) -> File {
+        File::open(path_str).unwrap()
+    }
+
+    fn collection() -> Vec<String> {
+        vec![String::from("header")]
+    }
+
+    #[test]
+    fn with_lf() {
+        let file = sut("example.txt");
+        let mut strings = collection();
+        file.read_lines_extend(&mut strings).unwrap();
+        assert_eq!(
+            strings,
+            vec![String::from("header"), String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let file = sut("example-with-crlf.txt");
+        let mut strings = collection();
+        file.read_lines_extend(&mut strings).unwrap();
+        assert_eq!(
+            strings,
+            vec![String::from("header"), String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let file = sut("example-with-indent.txt");
+        let mut strings = collection();
+        file.read_lines_extend(&mut strings).unwrap();
+        assert_eq!(
+            strings,
+            vec![String::from("header"), String::from("    lorem"), String::from("    ipsum")]
+        );
+    }
+
+}
@@ -0,0 +1,94 @@
+use std::fs::File;
+use crate::checkpoint::Checkpoint;
+use crate::options::*;
+use crate::traits::*;
+
+impl ReadLinesFromOffsetOnRefSelf for File {
+
+    /// Read lines into Vec<String> from a checkpoint; keep each line end `\n` or `\r\n`,
+    /// and return a checkpoint at the end of the last line.
+    /// 
+    /// A last line without a line ending is not read, because more of
+    /// the line may be appended; the next read gets the whole line.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use read_lines_into::Checkpoint;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let (strings, checkpoint): (Vec<String>, Checkpoint) = file.read_lines_from_offset(Checkpoint::new()).unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately, including when the
+    /// checkpoint is for a different file, or the file is truncated.
+    /// An error's byte offset is from the start of the file, but its
+    /// line number is from the checkpoint, i.e. line 1 is the first
+    /// line after the checkpoint, because a checkpoint has no line count.
+    /// 
+    fn read_lines_from_offset(&self, checkpoint: Checkpoint) -> crate::Result<(Vec<String>, Checkpoint)> {
+        ReadLinesOptions::new().read_file_from_offset(self, checkpoint)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    fn sut(path_str: &str) -> File {
+        File::open(path_str).unwrap()
+    }
+
+    #[test]
+    fn with_lf() {
+        let file = sut("example.txt");
+        let (strings, checkpoint) = file.read_lines_from_offset(Checkpoint::new()).unwrap();
+        assert_eq!(strings, vec![String::from("lorem\n"), String::from("ipsum\n")]);
+        assert_eq!(checkpoint.offset, 12);
+    }
+
+    #[test]
+    fn with_crlf() {
+        let file = sut("example-with-crlf.txt");
+        let (strings, checkpoint) = file.read_lines_from_offset(Checkpoint::new()).unwrap();
+        assert_eq!(strings, vec![String::from("lorem\r\n"), String::from("ipsum\r\n")]);
+        assert_eq!(checkpoint.offset, 14);
+    }
+
+    #[test]
+    fn with_indent() {
+        let file = sut("example-with-indent.txt");
+        let (strings, checkpoint) = file.read_lines_from_offset(Checkpoint::new()).unwrap();
+        assert_eq!(strings, vec![String::from("    lorem\n"), String::from("    ipsum\n")]);
+        assert_eq!(checkpoint.offset, 20);
+    }
+
+    #[test]
+    fn with_checkpoint_in_the_middle() {
+        let file = sut("example.txt");
+        let checkpoint = Checkpoint::of(&file.metadata().unwrap(), 6);
+        let (strings, checkpoint) = file.read_lines_from_offset(checkpoint).unwrap();
+        assert_eq!(strings, vec![String::from("ipsum\n")]);
+        assert_eq!(checkpoint.offset, 12);
+    }
+
+    #[test]
+    fn with_invalid_utf8_after_checkpoint() {
+        let file = sut("example-with-latin1.txt");
+        let checkpoint = Checkpoint::of(&file.metadata().unwrap(), 6);
+        let e = file.read_lines_from_offset(checkpoint).unwrap_err();
+        assert!(matches!(e, Error::InvalidUtf8 { line: Some(1), byte_offset: 15, .. }));
+    }
+
+    #[test]
+    fn with_other_file() {
+        let checkpoint = Checkpoint::of(&sut("example.txt").metadata().unwrap(), 6);
+        let file = sut("example-with-crlf.txt");
+        if cfg!(unix) {
+            assert!(file.read_lines_from_offset(checkpoint).is_err());
+        }
+    }
+
+}
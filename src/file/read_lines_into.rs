@@ -0,0 +1,68 @@
+use std::fs::File;
+use std::io::BufReader;
+use crate::traits::*;
+
+impl ReadLinesIntoOnRefSelf for File {
+
+    /// Read lines into any collection of strings, such as HashSet<String>,
+    /// BTreeSet<String>, or VecDeque<String>; clip each line end.
+    /// 
+    /// Clipping means that lines that differ only in their line end,
+    /// such as `lorem\n` and `lorem\r\n`, are the same string.
+    /// To keep line ends, or to trim, use `read_lines_into_with_options`.
+    /// 
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use std::fs::File;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let set = file.read_lines_into::<BTreeSet<String>>().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into<C: FromIterator<String>>(&self) -> crate::Result<C> {
+        let buf_reader = BufReader::new(self);
+        buf_reader.read_lines_into()
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    fn sut(path_str: &str) -> File {
+        File::open(path_str).unwrap()
+    }
+
+    #[test]
+    fn with_lf() {
+        let file = sut("example.txt");
+        assert_eq!(
+            file.read_lines_into::<VecDeque<String>>().unwrap(),
+            VecDeque::from([String::from("lorem"), String::from("ipsum")])
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let file = sut("example-with-crlf.txt");
+        assert_eq!(
+            file.read_lines_into::<VecDeque<String>>().unwrap(),
+            VecDeque::from([String::from("lorem"), String::from("ipsum")])
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let file = sut("example-with-indent.txt");
+        assert_eq!(
+            file.read_lines_into::<VecDeque<String>>().unwrap(),
+            VecDeque::from([String::from("    lorem"), String::from("    ipsum")])
+        );
+    }
+
+}
@@ -2,7 +2,7 @@ use std::fs::File;
 use std::io::BufReader;
 use crate::traits::*;
 
-impl ReadLinesIntoStringOnSelf for File {
+impl ReadLinesIntoStringOnRefSelf for File {
 
     /// Read lines into String; keep each line end `\n` or `\r\n`.
     /// 
@@ -16,7 +16,7 @@ impl ReadLinesIntoStringOnSelf for File {
     /// 
     /// Any error will return immediately.
     /// 
-    fn read_lines_into_string(self) -> ::std::io::Result<String> {
+    fn read_lines_into_string(&self) -> crate::Result<String> {
         let buf_reader = BufReader::new(self);
         buf_reader.read_lines_into_string()
     }
@@ -2,7 +2,7 @@ use std::fs::File;
 use std::io::BufReader;
 use crate::traits::*;
 
-impl ReadLinesIntoStringWithClipOnSelf for File {
+impl ReadLinesIntoStringWithClipOnRefSelf for File {
 
     /// Read lines into String; clip each line end `\n` or `\r\n`.
     /// 
@@ -16,7 +16,7 @@ impl ReadLinesIntoStringWithClipOnSelf for File {
     /// 
     /// Any error will return immediately.
     /// 
-    fn read_lines_into_string_with_clip(self) -> ::std::io::Result<String> {
+    fn read_lines_into_string_with_clip(&self) -> crate::Result<String> {
         let buf_reader = BufReader::new(self);
         buf_reader.read_lines_into_string_with_clip()
     }
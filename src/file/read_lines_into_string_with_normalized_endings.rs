@@ -0,0 +1,66 @@
+use std::fs::File;
+use std::io::BufReader;
+use crate::line_ending::NewlineStyle;
+use crate::traits::*;
+
+impl ReadLinesIntoStringWithNormalizedEndingsOnRefSelf for File {
+
+    /// Read lines into String; replace each line end `\n` or `\r\n` with the given newline style, LF or CRLF.
+    /// 
+    /// A last line without a line end stays without a line end,
+    /// so the result ends with a newline if and only if the source does.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use read_lines_into::NewlineStyle;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example-with-crlf.txt").unwrap();
+    /// let string: String = file.read_lines_into_string_with_normalized_endings(NewlineStyle::Lf).unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_string_with_normalized_endings(&self, style: NewlineStyle) -> crate::Result<String> {
+        let buf_reader = BufReader::new(self);
+        buf_reader.read_lines_into_string_with_normalized_endings(style)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sut(path_str: &str) -> File {
+        File::open(path_str).unwrap()
+    }
+
+    #[test]
+    fn with_lf() {
+        let file = sut("example.txt");
+        assert_eq!(
+            file.read_lines_into_string_with_normalized_endings(NewlineStyle::CrLf).unwrap(),
+            "lorem\r\nipsum\r\n"
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let file = sut("example-with-crlf.txt");
+        assert_eq!(
+            file.read_lines_into_string_with_normalized_endings(NewlineStyle::Lf).unwrap(),
+            "lorem\nipsum\n"
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let file = sut("example-with-indent.txt");
+        assert_eq!(
+            file.read_lines_into_string_with_normalized_endings(NewlineStyle::CrLf).unwrap(),
+            "    lorem\r\n    ipsum\r\n"
+        );
+    }
+
+}
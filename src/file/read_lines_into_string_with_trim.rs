@@ -2,7 +2,7 @@ use std::fs::File;
 use std::io::BufReader;
 use crate::traits::*;
 
-impl ReadLinesIntoStringWithTrimOnSelf for File {
+impl ReadLinesIntoStringWithTrimOnRefSelf for File {
 
     /// Read lines into String; trim each line of whitespace.
     /// 
@@ -16,7 +16,7 @@ impl ReadLinesIntoStringWithTrimOnSelf for File {
     /// 
     /// Any error will return immediately.
     /// 
-    fn read_lines_into_string_with_trim(self) -> ::std::io::Result<String> {
+    fn read_lines_into_string_with_trim(&self) -> crate::Result<String> {
         let buf_reader = BufReader::new(self);
         buf_reader.read_lines_into_string_with_trim()
     }
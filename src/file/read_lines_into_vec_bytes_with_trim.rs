@@ -0,0 +1,61 @@
+use std::fs::File;
+use std::io::BufReader;
+use crate::traits::*;
+
+impl ReadLinesIntoVecBytesWithTrimOnRefSelf for File {
+
+    /// Read lines into Vec<Vec<u8>>, without UTF-8 validation; clip each line end `\n` or `\r\n`, and trim each line of ASCII whitespace.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let lines: Vec<Vec<u8>> = file.read_lines_into_vec_bytes_with_trim().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_vec_bytes_with_trim(&self) -> crate::Result<Vec<Vec<u8>>> {
+        let buf_reader = BufReader::new(self);
+        buf_reader.read_lines_into_vec_bytes_with_trim()
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sut(path_str: &str) -> File {
+        File::open(path_str).unwrap()
+    }
+
+    #[test]
+    fn with_lf() {
+        let file = sut("example.txt");
+        assert_eq!(
+            file.read_lines_into_vec_bytes_with_trim().unwrap(),
+            vec![b"lorem".to_vec(), b"ipsum".to_vec()]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let file = sut("example-with-crlf.txt");
+        assert_eq!(
+            file.read_lines_into_vec_bytes_with_trim().unwrap(),
+            vec![b"lorem".to_vec(), b"ipsum".to_vec()]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let file = sut("example-with-indent.txt");
+        assert_eq!(
+            file.read_lines_into_vec_bytes_with_trim().unwrap(),
+            vec![b"lorem".to_vec(), b"ipsum".to_vec()]
+        );
+    }
+
+}
@@ -0,0 +1,68 @@
+use std::fs::File;
+use std::io::BufReader;
+use crate::line::Line;
+use crate::traits::*;
+
+impl ReadLinesIntoLinesOnRefSelf for File {
+
+    /// Read lines into Vec<Line>; each line record has its line number,
+    /// byte offset, content, and line ending.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use read_lines_into::line::Line;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let lines: Vec<Line> = file.read_lines_into_vec_line().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_vec_line(&self) -> crate::Result<Vec<Line>> {
+        let buf_reader = BufReader::new(self);
+        buf_reader.read_lines_into_vec_line()
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sut(path_str: &str) -> File {
+        File::open(path_str).unwrap()
+    }
+
+    fn with_endings(lines: Vec<Line>) -> Vec<String> {
+        lines.iter().map(Line::with_ending).collect()
+    }
+
+    #[test]
+    fn with_lf() {
+        let file = sut("example.txt");
+        assert_eq!(
+            with_endings(file.read_lines_into_vec_line().unwrap()),
+            vec![String::from("lorem\n"), String::from("ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let file = sut("example-with-crlf.txt");
+        assert_eq!(
+            with_endings(file.read_lines_into_vec_line().unwrap()),
+            vec![String::from("lorem\r\n"), String::from("ipsum\r\n")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let file = sut("example-with-indent.txt");
+        assert_eq!(
+            with_endings(file.read_lines_into_vec_line().unwrap()),
+            vec![String::from("    lorem\n"), String::from("    ipsum\n")]
+        );
+    }
+
+}
@@ -0,0 +1,66 @@
+use std::fs::File;
+use std::io::BufReader;
+use std::str::FromStr;
+use crate::parse_lines_error::ParseLinesError;
+use crate::traits::*;
+
+impl ReadLinesIntoParsedOnRefSelf for File {
+
+    /// Read lines into Vec<T>; clip each line end `\n` or `\r\n`, trim each line of whitespace, then parse each line using `FromStr`.
+    /// 
+    /// To skip blank lines and comment lines, use `ReadLinesOptions::read_parsed`.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let strings: Vec<String> = file.read_lines_into_vec_parsed().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately. A parse error
+    /// has the line number, the line, and the `FromStr` error.
+    /// 
+    fn read_lines_into_vec_parsed<T: FromStr>(&self) -> Result<Vec<T>, ParseLinesError<T::Err>> {
+        let buf_reader = BufReader::new(self);
+        buf_reader.read_lines_into_vec_parsed()
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sut(path_str: &str) -> File {
+        File::open(path_str).unwrap()
+    }
+
+    #[test]
+    fn with_lf() {
+        let file = sut("example.txt");
+        assert_eq!(
+            file.read_lines_into_vec_parsed::<String>().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let file = sut("example-with-crlf.txt");
+        assert_eq!(
+            file.read_lines_into_vec_parsed::<String>().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let file = sut("example-with-indent.txt");
+        assert_eq!(
+            file.read_lines_into_vec_parsed::<String>().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+}
@@ -2,7 +2,7 @@ use std::fs::File;
 use std::io::BufReader;
 use crate::traits::*;
 
-impl ReadLinesIntoStringsOnSelf for File {
+impl ReadLinesIntoStringsOnRefSelf for File {
 
     /// Read lines into Vec<String>; keep each line end `\n` or `\r\n`.
     /// 
@@ -16,7 +16,7 @@ impl ReadLinesIntoStringsOnSelf for File {
     /// 
     /// Any error will return immediately.
     /// 
-    fn read_lines_into_vec_string(self) -> ::std::io::Result<Vec<String>> {
+    fn read_lines_into_vec_string(&self) -> crate::Result<Vec<String>> {
         let buf_reader = BufReader::new(self);
         buf_reader.read_lines_into_vec_string()
     }
@@ -0,0 +1,65 @@
+use std::fs::File;
+use std::io::BufReader;
+use crate::traits::*;
+
+impl ReadLinesIntoStringsWithDedentOnRefSelf for File {
+
+    /// Read lines into Vec<String>; remove the common leading indentation from all lines, and keep each line end `\n` or `\r\n`.
+    /// 
+    /// The common indentation is the longest run of spaces and tabs
+    /// that starts every line that isn't blank, like Python's `textwrap.dedent`.
+    /// Nested indentation is kept.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example-with-indent.txt").unwrap();
+    /// let strings: Vec<String> = file.read_lines_into_vec_string_with_dedent().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_vec_string_with_dedent(&self) -> crate::Result<Vec<String>> {
+        let buf_reader = BufReader::new(self);
+        buf_reader.read_lines_into_vec_string_with_dedent()
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sut(path_str: &str) -> File {
+        File::open(path_str).unwrap()
+    }
+
+    #[test]
+    fn with_lf() {
+        let file = sut("example.txt");
+        assert_eq!(
+            file.read_lines_into_vec_string_with_dedent().unwrap(),
+            vec![String::from("lorem\n"), String::from("ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let file = sut("example-with-crlf.txt");
+        assert_eq!(
+            file.read_lines_into_vec_string_with_dedent().unwrap(),
+            vec![String::from("lorem\r\n"), String::from("ipsum\r\n")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let file = sut("example-with-indent.txt");
+        assert_eq!(
+            file.read_lines_into_vec_string_with_dedent().unwrap(),
+            vec![String::from("lorem\n"), String::from("ipsum\n")]
+        );
+    }
+
+}
@@ -2,7 +2,7 @@ use std::fs::File;
 use std::io::BufReader;
 use crate::traits::*;
 
-impl ReadLinesIntoStringsWithTrimOnSelf for File {
+impl ReadLinesIntoStringsWithTrimOnRefSelf for File {
 
     /// Read lines into Vec<String>; trim each line of whitespace.
     /// 
@@ -16,7 +16,7 @@ impl ReadLinesIntoStringsWithTrimOnSelf for File {
     /// 
     /// Any error will return immediately.
     /// 
-    fn read_lines_into_vec_string_with_trim(self) -> ::std::io::Result<Vec<String>> {
+    fn read_lines_into_vec_string_with_trim(&self) -> crate::Result<Vec<String>> {
         let buf_reader = BufReader::new(self);
         buf_reader.read_lines_into_vec_string_with_trim()
     }
@@ -0,0 +1,68 @@
+use std::fs::File;
+use std::io::BufReader;
+use crate::options::*;
+use crate::traits::*;
+
+impl ReadLinesIntoWithOptionsOnRefSelf for File {
+
+    /// Read lines into any collection of strings, such as String or Vec<String>, with options.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use read_lines_into::options::*;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// let options = ReadLinesOptions::new().endings(Endings::Clip);
+    /// let strings: Vec<String> = file.read_lines_into_with_options(&options).unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_with_options<C: FromIterator<String>>(&self, options: &ReadLinesOptions) -> crate::Result<C> {
+        let buf_reader = BufReader::new(self);
+        buf_reader.read_lines_into_with_options(options)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sut(path_str: &str) -> File {
+        File::open(path_str).unwrap()
+    }
+
+    fn options() -> ReadLinesOptions {
+        ReadLinesOptions::new().endings(Endings::Clip).whitespace(Whitespace::TrimEnd)
+    }
+
+    #[test]
+    fn with_lf() {
+        let file = sut("example.txt");
+        assert_eq!(
+            file.read_lines_into_with_options::<Vec<String>>(&options()).unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let file = sut("example-with-crlf.txt");
+        assert_eq!(
+            file.read_lines_into_with_options::<Vec<String>>(&options()).unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let file = sut("example-with-indent.txt");
+        assert_eq!(
+            file.read_lines_into_with_options::<Vec<String>>(&options()).unwrap(),
+            vec![String::from("    lorem"), String::from("    ipsum")]
+        );
+    }
+
+}
@@ -0,0 +1,77 @@
+use std::fs::File;
+use std::io::BufReader;
+use crate::iter::RevLines;
+use crate::traits::*;
+
+impl RevLinesOnRefSelf for File {
+
+    /// Iterate lines in reverse, from last to first, one String at a time; keep each line end `\n` or `\r\n`.
+    /// 
+    /// The iterator reads from a clone of the file handle,
+    /// which shares the file's read position.
+    /// 
+    /// ```
+    /// use std::fs::File;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let file: File = File::open("example.txt").unwrap();
+    /// for line in file.rev_lines().unwrap() {
+    ///     let string: String = line.unwrap();
+    /// }
+    /// ```
+    /// 
+    /// Any error cloning the file handle will return immediately;
+    /// any error reading is yielded as an item.
+    /// 
+    fn rev_lines(&self) -> crate::Result<RevLines<BufReader<File>>> {
+        let buf_reader = BufReader::new(self.try_clone()?);
+        Ok(buf_reader.rev_lines())
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sut(path_str: &str) -> File {
+        File::open(path_str).unwrap()
+    }
+
+    #[test]
+    fn with_lf() {
+        let file = sut("example.txt");
+        assert_eq!(
+            file.rev_lines().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("ipsum\n"), String::from("lorem\n")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let file = sut("example-with-crlf.txt");
+        assert_eq!(
+            file.rev_lines().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("ipsum\r\n"), String::from("lorem\r\n")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let file = sut("example-with-indent.txt");
+        assert_eq!(
+            file.rev_lines().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("    ipsum\n"), String::from("    lorem\n")]
+        );
+    }
+
+    #[test]
+    fn without_final_newline() {
+        let file = sut("example-without-final-newline.txt");
+        assert_eq!(
+            file.rev_lines().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("ipsum"), String::from("lorem\n")]
+        );
+    }
+
+}
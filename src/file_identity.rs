@@ -0,0 +1,17 @@
+//! The identity of a file, to know when a path has a new file,
+//! such as after log rotation.
+
+use std::fs::Metadata;
+
+/// Get the device and inode of a file.
+#[cfg(unix)]
+pub(crate) fn file_identity(metadata: &Metadata) -> Option<(u64, u64)> {
+    use std::os::unix::fs::MetadataExt;
+    Some((metadata.dev(), metadata.ino()))
+}
+
+/// Get the device and inode of a file; elsewhere than Unix, there are none.
+#[cfg(not(unix))]
+pub(crate) fn file_identity(_metadata: &Metadata) -> Option<(u64, u64)> {
+    None
+}
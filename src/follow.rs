@@ -0,0 +1,653 @@
+//! Follow a file as it grows, like `tail -F`, such as for log shippers.
+//!
+//! A `Follow` iterator reads lines from a path, and at the end of the
+//! file, polls for appended data, rather than stopping. It yields only
+//! complete lines: a partial last line waits until its line terminator
+//! arrives.
+//!
+//! When the file is rotated, i.e. the path gets a new file, such as by
+//! `logrotate`, then the iterator reads the old file to its end, drops
+//! any partial last line of the old file, then reopens the path, and
+//! reads the new file from its start. When the file is truncated,
+//! then the iterator reads it again from its start.
+//!
+//! Rotation is found by the file's device and inode, so only on Unix.
+//! Elsewhere, the iterator can't tell when the path gets a new file,
+//! and finds only truncation, by the file getting shorter than the
+//! bytes read; a new file that is already longer isn't found.
+//!
+//! ```
+//! use std::time::Duration;
+//! use read_lines_into::traits::*;
+//!
+//! let lines = "example.txt".follow()
+//!     .poll_interval(Duration::from_millis(10))
+//!     .idle_timeout(Duration::from_millis(50));
+//! for line in lines {
+//!     let string: String = line.unwrap();
+//! }
+//! ```
+//!
+//! This polls, because it has no dependencies; it doesn't use
+//! `inotify` or any other notification API.
+
+use std::fs::File;
+use std::io::BufReader;
+use std::io::ErrorKind;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
+use crate::error::Error;
+use crate::error::Limit;
+use crate::file_identity::file_identity;
+use crate::limits::LimitAction;
+use crate::limits::Limits;
+use crate::limits::truncate_point;
+use crate::line_ending::LineEnding;
+use crate::line_reader::SKIP_CHUNK_LEN;
+use crate::line_terminators::LineTerminators;
+use crate::line_terminators::MAX_TERMINATOR_LEN;
+use crate::line_terminators::read_line_bytes_max;
+
+/// An iterator that follows a file as it grows; keep each line end, such as `\n` or `\r\n`.
+#[derive(Debug)]
+pub struct Follow {
+    path: PathBuf,
+    poll_interval: Duration,
+    idle_timeout: Option<Duration>,
+    from_end: bool,
+    terminators: LineTerminators,
+    limits: Limits,
+    reader: Option<BufReader<File>>,
+    /// The device and inode of the open file, where known.
+    identity: Option<(u64, u64)>,
+    /// The bytes read from the open file, including the current line.
+    offset: u64,
+    /// The byte offset in the open file of the start of the current line.
+    start: u64,
+    /// The lines read from the open file.
+    number: usize,
+    /// The bytes of a line whose line terminator hasn't arrived yet.
+    partial: Vec<u8>,
+    /// Set when the rest of a line that is too long is being skipped,
+    /// with what to do with the line at its line terminator.
+    skipping: Option<LimitAction>,
+    /// The last bytes skipped, in case they are the start of a line terminator.
+    skipped: Vec<u8>,
+    /// The lines read from all files, for the limits.
+    lines: usize,
+    /// The bytes read from all files, for the limits.
+    total_bytes: u64,
+    /// Set when a limit stops the iterator.
+    done: bool,
+    /// When data last arrived, for the idle timeout.
+    last_data: Instant,
+    /// Set when the path has a new file, and the old file is
+    /// being read to its end, before reopening the path.
+    rotated: bool,
+}
+
+impl Follow {
+
+    /// Create an iterator that follows the file at a path.
+    ///
+    /// The file doesn't need to exist yet: the iterator waits for it.
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            poll_interval: Duration::from_millis(250),
+            idle_timeout: None,
+            from_end: false,
+            terminators: LineTerminators::DEFAULT,
+            limits: Limits::default(),
+            reader: None,
+            identity: None,
+            offset: 0,
+            start: 0,
+            number: 0,
+            partial: Vec::new(),
+            skipping: None,
+            skipped: Vec::new(),
+            lines: 0,
+            total_bytes: 0,
+            done: false,
+            last_data: Instant::now(),
+            rotated: false,
+        }
+    }
+
+    /// Set how long to wait between polls at the end of the file.
+    ///
+    /// The default is 250 milliseconds.
+    pub fn poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Set how long to wait for data before the iterator returns `None`.
+    ///
+    /// The default is to wait forever. After `None`, calling `next`
+    /// again continues to follow the file.
+    pub fn idle_timeout(mut self, idle_timeout: Duration) -> Self {
+        self.idle_timeout = Some(idle_timeout);
+        self
+    }
+
+    /// Set whether to start at the end of the file, like `tail -f`,
+    /// so the iterator yields only lines appended from now on.
+    ///
+    /// The default is to start at the start of the file.
+    /// This applies only to the file at the path when the iterator
+    /// first tries to open it: a file that doesn't exist yet, or that
+    /// is rotated in, is always read from its start.
+    pub fn from_end(mut self, from_end: bool) -> Self {
+        self.from_end = from_end;
+        self
+    }
+
+    /// Set which line terminators split lines.
+    ///
+    /// The default is `LineTerminators::DEFAULT`, which is LF and CRLF.
+    pub fn terminators(mut self, terminators: LineTerminators) -> Self {
+        self.terminators = terminators;
+        self
+    }
+
+    /// Set resource limits, such as the maximum bytes in one line.
+    ///
+    /// The lines and the total bytes count over every file read, such
+    /// as across rotation; when either limit is exceeded, the iterator
+    /// stops. A line that is too long, with `LimitAction::Error`, is
+    /// yielded as an error, then skipped, and the iterator continues.
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Get the byte offset in the open file of the end of the last
+    /// line read. This starts again at 0 when the path gets a new file,
+    /// such as after rotation, and when the file is truncated.
+    pub fn byte_offset(&self) -> u64 {
+        self.start
+    }
+
+    /// Open the path, if it exists.
+    fn open(&mut self) -> crate::Result<bool> {
+        let from_end = ::std::mem::take(&mut self.from_end);
+        let mut file = match File::open(&self.path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(false),
+            Err(e) => return Err(Error::open(&self.path, e)),
+        };
+        let metadata = file.metadata().map_err(|e| self.read_error(e))?;
+        self.identity = file_identity(&metadata);
+        self.offset = 0;
+        if from_end {
+            self.offset = file.seek(SeekFrom::End(0)).map_err(|e| self.read_error(e))?;
+        }
+        self.reader = Some(BufReader::new(file));
+        self.restart();
+        Ok(true)
+    }
+
+    /// Start reading lines again, from the start of the file or the offset.
+    fn restart(&mut self) {
+        self.start = self.offset;
+        self.number = 0;
+        self.partial.clear();
+        self.skipping = None;
+    }
+
+    /// Read more of the current line into `partial`, or, when the rest
+    /// of the line is being skipped, past it.
+    ///
+    /// Return the line ending that ends the line, or `LineEnding::None`
+    /// at the end of the file, or `None` if the line is too long.
+    fn read(&mut self) -> crate::Result<Option<LineEnding>> {
+        let Some(reader) = self.reader.as_mut() else {
+            return Ok(Some(LineEnding::None));
+        };
+        let room = self.limits.max_total_bytes.map_or(u64::MAX, |(max, _)| max.saturating_sub(self.total_bytes).saturating_add(1));
+        let room = usize::try_from(room).unwrap_or(usize::MAX);
+        let (buf, keep, cap) = if self.skipping.is_some() {
+            let keep = self.skipped.len().min(MAX_TERMINATOR_LEN - 1);
+            self.skipped.drain(..self.skipped.len() - keep);
+            (&mut self.skipped, keep, keep + SKIP_CHUNK_LEN.min(room))
+        } else {
+            let len = self.partial.len();
+            let cap = self.limits.max_line_bytes.map_or(usize::MAX, |(max, _)| max.saturating_add(MAX_TERMINATOR_LEN));
+            (&mut self.partial, len, cap.min(len.saturating_add(room)))
+        };
+        let ending = read_line_bytes_max(reader, self.terminators, buf, cap);
+        let read = (buf.len() - keep) as u64;
+        self.offset += read;
+        self.total_bytes += read;
+        if read > 0 {
+            self.last_data = Instant::now();
+        }
+        ending.map_err(|e| self.read_error(e))
+    }
+
+    /// Handle a line that is longer than the maximum line bytes,
+    /// either as an error, or by truncating it.
+    ///
+    /// Return the error, if any.
+    fn too_long(&mut self, ending: Option<LineEnding>) -> Option<Error> {
+        let (max, action) = self.limits.max_line_bytes?;
+        match action {
+            LimitAction::Error => {
+                let e = Error::LimitExceeded { path: Some(self.path.clone()), line: Some(self.number + 1), limit: Limit::LineBytes(max) };
+                self.partial.clear();
+                match ending {
+                    Some(_) => self.end_line(),
+                    None => self.skipping = Some(action),
+                }
+                Some(e)
+            }
+            LimitAction::Truncate => {
+                self.partial.truncate(truncate_point(&self.partial, max));
+                match ending {
+                    Some(ending) => self.partial.extend_from_slice(ending.as_str().as_bytes()),
+                    None => self.skipping = Some(action),
+                }
+                None
+            }
+        }
+    }
+
+    /// Take the partial line as a complete line.
+    fn take_line(&mut self) -> crate::Result<String> {
+        let bytes = ::std::mem::take(&mut self.partial);
+        let start = self.start;
+        self.end_line();
+        String::from_utf8(bytes).map_err(|e| Error::InvalidUtf8 {
+            path: Some(self.path.clone()),
+            line: Some(self.number),
+            byte_offset: start + e.utf8_error().valid_up_to() as u64,
+        })
+    }
+
+    /// Count a line as read, and start the next line.
+    fn end_line(&mut self) {
+        self.number += 1;
+        self.lines += 1;
+        self.start = self.offset;
+        self.skipped.clear();
+    }
+
+    /// Stop the iterator, because a limit is exceeded.
+    fn exceeded(&mut self, limit: Limit, action: LimitAction) -> Option<crate::Result<String>> {
+        self.done = true;
+        self.partial.clear();
+        match action {
+            LimitAction::Error => Some(Err(Error::LimitExceeded { path: Some(self.path.clone()), line: Some(self.number + 1), limit })),
+            LimitAction::Truncate => None,
+        }
+    }
+
+    /// At the end of the file, check whether the path has a new file,
+    /// or the file is truncated, or neither.
+    fn check_rotation(&mut self) -> crate::Result<Rotation> {
+        let metadata = match ::std::fs::metadata(&self.path) {
+            Ok(metadata) => metadata,
+            // The file is moved away, and the new file isn't there yet.
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Rotation::None),
+            Err(e) => return Err(self.read_error(e)),
+        };
+        if file_identity(&metadata) != self.identity {
+            return Ok(Rotation::Rotated);
+        }
+        if metadata.len() < self.offset {
+            return Ok(Rotation::Truncated);
+        }
+        Ok(Rotation::None)
+    }
+
+    /// Wait for more data, or return false if the idle timeout is over.
+    fn wait(&mut self) -> bool {
+        if self.idle_timeout.is_some_and(|x| self.last_data.elapsed() >= x) {
+            self.last_data = Instant::now();
+            return false;
+        }
+        ::std::thread::sleep(self.poll_interval);
+        true
+    }
+
+    fn read_error(&self, e: ::std::io::Error) -> Error {
+        Error::Read { path: Some(self.path.clone()), line: Some(self.number + 1), source: e }
+    }
+
+}
+
+/// What changed at the path, at the end of the file.
+enum Rotation {
+    None,
+    Rotated,
+    Truncated,
+}
+
+impl Iterator for Follow {
+    type Item = crate::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if self.done {
+                return None;
+            }
+            if self.reader.is_none() {
+                match self.open() {
+                    Ok(true) => {}
+                    Ok(false) => {
+                        if !self.wait() {
+                            return None;
+                        }
+                        continue;
+                    }
+                    Err(e) => return Some(Err(e)),
+                }
+            }
+            if let Some((max, LimitAction::Truncate)) = self.limits.max_lines {
+                if self.lines >= max {
+                    return self.exceeded(Limit::Lines(max), LimitAction::Truncate);
+                }
+            }
+            let ending = match self.read() {
+                Ok(ending) => ending,
+                Err(e) => return Some(Err(e)),
+            };
+            if let Some((max, action)) = self.limits.max_total_bytes {
+                if self.total_bytes > max {
+                    return self.exceeded(Limit::TotalBytes(max), action);
+                }
+            }
+            if let Some((max, action)) = self.limits.max_lines {
+                if self.lines >= max && !self.partial.is_empty() {
+                    return self.exceeded(Limit::Lines(max), action);
+                }
+            }
+            match (ending, self.skipping) {
+                (Some(LineEnding::None), _) => {}
+                (Some(_), Some(LimitAction::Error)) => {
+                    // The line is already yielded as an error.
+                    self.skipping = None;
+                    self.end_line();
+                    continue;
+                }
+                (Some(ending), skipping) => {
+                    self.skipping = None;
+                    let too_long = skipping.is_none() && self.limits.max_line_bytes
+                        .is_some_and(|(max, _)| self.partial.len() - ending.as_str().len() > max);
+                    if too_long {
+                        if let Some(e) = self.too_long(Some(ending)) {
+                            return Some(Err(e));
+                        }
+                    } else if skipping.is_some() {
+                        self.partial.extend_from_slice(ending.as_str().as_bytes());
+                    }
+                    return Some(self.take_line());
+                }
+                (None, Some(_)) => continue,
+                (None, None) => {
+                    if let Some(e) = self.too_long(None) {
+                        return Some(Err(e));
+                    }
+                    continue;
+                }
+            }
+            if self.rotated {
+                // The old file is read to its end, and its partial
+                // last line won't get a line terminator now.
+                self.rotated = false;
+                self.reader = None;
+                self.partial.clear();
+                continue;
+            }
+            match self.check_rotation() {
+                Ok(Rotation::Rotated) => {
+                    // Read lines appended to the old file before it was rotated.
+                    self.rotated = true;
+                }
+                Ok(Rotation::Truncated) => {
+                    let reader = self.reader.as_mut()?;
+                    if let Err(e) = reader.seek(SeekFrom::Start(0)) {
+                        return Some(Err(self.read_error(e)));
+                    }
+                    self.offset = 0;
+                    self.restart();
+                }
+                Ok(Rotation::None) => {
+                    if !self.wait() {
+                        return None;
+                    }
+                }
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::thread;
+    use crate::test_util::append;
+    use crate::test_util::temp_path;
+
+    fn follow(path: &Path) -> Follow {
+        Follow::new(path)
+            .poll_interval(Duration::from_millis(5))
+            .idle_timeout(Duration::from_secs(5))
+    }
+
+    fn pause() {
+        thread::sleep(Duration::from_millis(50));
+    }
+
+    #[test]
+    fn yields_appended_lines_and_buffers_partial_line() {
+        let path = temp_path("appended");
+        append(&path, "lorem\n");
+        let writer = {
+            let path = path.clone();
+            thread::spawn(move || {
+                pause();
+                append(&path, "ipsum\r\ndo");
+                pause();
+                append(&path, "lor\n");
+            })
+        };
+        let lines: Vec<String> = follow(&path).take(3).map(|x| x.unwrap()).collect();
+        writer.join().unwrap();
+        assert_eq!(lines, vec!["lorem\n", "ipsum\r\n", "dolor\n"]);
+        ::std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn waits_for_the_file_to_exist() {
+        let path = temp_path("missing");
+        let writer = {
+            let path = path.clone();
+            thread::spawn(move || {
+                pause();
+                append(&path, "lorem\n");
+            })
+        };
+        let lines: Vec<String> = follow(&path).take(1).map(|x| x.unwrap()).collect();
+        writer.join().unwrap();
+        assert_eq!(lines, vec!["lorem\n"]);
+        ::std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn from_end() {
+        let path = temp_path("from_end");
+        append(&path, "lorem\nipsum\n");
+        let writer = {
+            let path = path.clone();
+            thread::spawn(move || {
+                pause();
+                append(&path, "dolor\n");
+            })
+        };
+        let lines: Vec<String> = follow(&path).from_end(true).take(1).map(|x| x.unwrap()).collect();
+        writer.join().unwrap();
+        assert_eq!(lines, vec!["dolor\n"]);
+        ::std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn from_end_reads_a_file_created_later_from_its_start() {
+        let path = temp_path("from_end_missing");
+        let writer = {
+            let path = path.clone();
+            thread::spawn(move || {
+                pause();
+                append(&path, "lorem\n");
+            })
+        };
+        let lines: Vec<String> = follow(&path).from_end(true).take(1).map(|x| x.unwrap()).collect();
+        writer.join().unwrap();
+        assert_eq!(lines, vec!["lorem\n"]);
+        ::std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn reopens_after_rotation() {
+        let path = temp_path("rotation");
+        let rotated = temp_path("rotation.1");
+        append(&path, "lorem\nips");
+        let writer = {
+            let path = path.clone();
+            let rotated = rotated.clone();
+            thread::spawn(move || {
+                pause();
+                ::std::fs::rename(&path, &rotated).unwrap();
+                pause();
+                append(&path, "dolor\n");
+            })
+        };
+        let lines: Vec<String> = follow(&path).take(2).map(|x| x.unwrap()).collect();
+        writer.join().unwrap();
+        assert_eq!(lines, vec!["lorem\n", "dolor\n"]);
+        ::std::fs::remove_file(&path).unwrap();
+        ::std::fs::remove_file(&rotated).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn reads_old_file_to_end_after_rotation() {
+        let path = temp_path("rotation-to-end");
+        let rotated = temp_path("rotation-to-end.1");
+        append(&path, "lorem\n");
+        let mut lines = Follow::new(&path).poll_interval(Duration::from_millis(5)).idle_timeout(Duration::from_millis(20));
+        assert_eq!(lines.next().unwrap().unwrap(), "lorem\n");
+        assert!(lines.next().is_none());
+        append(&path, "ipsum\ndol");
+        ::std::fs::rename(&path, &rotated).unwrap();
+        append(&path, "sit\n");
+        assert_eq!(lines.next().unwrap().unwrap(), "ipsum\n");
+        assert_eq!(lines.next().unwrap().unwrap(), "sit\n");
+        ::std::fs::remove_file(&path).unwrap();
+        ::std::fs::remove_file(&rotated).unwrap();
+    }
+
+    #[test]
+    fn rereads_after_truncation() {
+        let path = temp_path("truncation");
+        append(&path, "lorem\nipsum\n");
+        let writer = {
+            let path = path.clone();
+            thread::spawn(move || {
+                pause();
+                ::std::fs::OpenOptions::new().write(true).truncate(true).open(&path).unwrap();
+                append(&path, "dolor\n");
+            })
+        };
+        let lines: Vec<String> = follow(&path).take(3).map(|x| x.unwrap()).collect();
+        writer.join().unwrap();
+        assert_eq!(lines, vec!["lorem\n", "ipsum\n", "dolor\n"]);
+        ::std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn limits_with_line_bytes_error() {
+        let path = temp_path("limits-error");
+        append(&path, "lorem\nipsum dolor\ndolor\n");
+        let mut lines = Follow::new(&path)
+            .poll_interval(Duration::from_millis(5))
+            .idle_timeout(Duration::from_millis(20))
+            .limits(Limits::new().max_line_bytes(5, LimitAction::Error));
+        assert_eq!(lines.next().unwrap().unwrap(), "lorem\n");
+        let e = lines.next().unwrap().unwrap_err();
+        assert!(matches!(e, Error::LimitExceeded { line: Some(2), limit: Limit::LineBytes(5), .. }));
+        assert_eq!(lines.next().unwrap().unwrap(), "dolor\n");
+        append(&path, &"a".repeat(100_000));
+        let e = lines.next().unwrap().unwrap_err();
+        assert!(matches!(e, Error::LimitExceeded { line: Some(4), limit: Limit::LineBytes(5), .. }));
+        assert!(lines.next().is_none());
+        assert!(lines.partial.is_empty() && lines.skipped.len() < MAX_TERMINATOR_LEN);
+        append(&path, "aaa\nsit\n");
+        assert_eq!(lines.next().unwrap().unwrap(), "sit\n");
+        ::std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn limits_with_line_bytes_truncate() {
+        let path = temp_path("limits-truncate");
+        append(&path, "lorem\nipsum dolor\n");
+        let mut lines = Follow::new(&path)
+            .poll_interval(Duration::from_millis(5))
+            .idle_timeout(Duration::from_millis(20))
+            .limits(Limits::new().max_line_bytes(5, LimitAction::Truncate));
+        assert_eq!(lines.next().unwrap().unwrap(), "lorem\n");
+        assert_eq!(lines.next().unwrap().unwrap(), "ipsum\n");
+        append(&path, &"a".repeat(100_000));
+        assert!(lines.next().is_none());
+        assert_eq!(lines.partial.len(), 5);
+        append(&path, "aaa\r\nsit\n");
+        assert_eq!(lines.next().unwrap().unwrap(), "aaaaa\r\n");
+        assert_eq!(lines.next().unwrap().unwrap(), "sit\n");
+        ::std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn limits_with_lines_and_total_bytes() {
+        let path = temp_path("limits-lines");
+        append(&path, "lorem\nipsum\ndolor\n");
+        let lines = |limits: Limits| {
+            Follow::new(&path)
+                .poll_interval(Duration::from_millis(5))
+                .idle_timeout(Duration::from_millis(20))
+                .limits(limits)
+                .collect::<Vec<crate::Result<String>>>()
+        };
+        for limits in [Limits::new().max_lines(2, LimitAction::Truncate), Limits::new().max_total_bytes(12, LimitAction::Truncate)] {
+            let strings = lines(limits).into_iter().map(|x| x.unwrap()).collect::<Vec<String>>();
+            assert_eq!(strings, vec!["lorem\n", "ipsum\n"]);
+        }
+        let results = lines(Limits::new().max_lines(2, LimitAction::Error));
+        assert!(matches!(results[2], Err(Error::LimitExceeded { line: Some(3), limit: Limit::Lines(2), .. })));
+        let results = lines(Limits::new().max_total_bytes(12, LimitAction::Error));
+        assert!(matches!(results[2], Err(Error::LimitExceeded { line: Some(3), limit: Limit::TotalBytes(12), .. })));
+        assert_eq!(results.len(), 3);
+        ::std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn idle_timeout() {
+        let path = temp_path("idle");
+        append(&path, "lorem\n");
+        let mut lines = Follow::new(&path).poll_interval(Duration::from_millis(5)).idle_timeout(Duration::from_millis(20));
+        assert_eq!(lines.next().unwrap().unwrap(), "lorem\n");
+        assert!(lines.next().is_none());
+        append(&path, "ipsum\n");
+        assert_eq!(lines.next().unwrap().unwrap(), "ipsum\n");
+        assert_eq!(lines.byte_offset(), 12);
+        ::std::fs::remove_file(&path).unwrap();
+    }
+
+}
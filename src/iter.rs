@@ -0,0 +1,457 @@
+//! Lazy line iterators, for processing huge files without collecting them.
+//!
+//! Each iterator reads one line at a time into an internal buffer,
+//! which is reused for every line, then yields a `Result<String>`.
+//!
+//! ```
+//! use std::io::Cursor;
+//! use read_lines_into::traits::*;
+//!
+//! let mut lines = Cursor::new("lorem\r\nipsum\n").lines_with_endings();
+//! assert_eq!(lines.next().unwrap().unwrap(), "lorem\r\n");
+//! assert_eq!(lines.next().unwrap().unwrap(), "ipsum\n");
+//! assert!(lines.next().is_none());
+//! ```
+
+use std::io::BufRead;
+use std::io::Read;
+use std::io::Seek;
+use std::path::Path;
+use std::path::PathBuf;
+use crate::limits::Limits;
+use crate::line_reader::LineReader;
+use crate::line_terminators::LineTerminators;
+use crate::reverse_line_reader::ReverseLineReader;
+
+/// An iterator over lines; keep each line end, such as `\n` or `\r\n`.
+#[derive(Debug)]
+pub struct LinesWithEndings<R> {
+    inner: LineReader<R>,
+}
+
+impl<R: BufRead> LinesWithEndings<R> {
+
+    /// Create an iterator over the lines of a `BufRead`.
+    pub fn new(reader: R) -> Self {
+        Self { inner: LineReader::new(reader) }
+    }
+
+    /// Set which line terminators split lines.
+    ///
+    /// The default is `LineTerminators::DEFAULT`, which is LF and CRLF.
+    pub fn terminators(self, terminators: LineTerminators) -> Self {
+        Self { inner: self.inner.terminators(terminators) }
+    }
+
+    /// Set resource limits, such as the maximum bytes in one line.
+    pub fn limits(self, limits: Limits) -> Self {
+        Self { inner: self.inner.limits(limits) }
+    }
+
+    /// Get the byte offset of the end of the last line read,
+    /// from the start of the reader, such as to resume reading later.
+    pub fn byte_offset(&self) -> u64 {
+        self.inner.byte_offset()
+    }
+
+    /// Get the underlying reader back.
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+
+}
+
+impl<R: BufRead> Iterator for LinesWithEndings<R> {
+    type Item = crate::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.read_next().map(|x| x.map(|line| line.with_ending()))
+    }
+}
+
+/// An iterator over lines; clip each line end, such as `\n` or `\r\n`.
+#[derive(Debug)]
+pub struct LinesWithClip<R> {
+    inner: LineReader<R>,
+}
+
+impl<R: BufRead> LinesWithClip<R> {
+
+    /// Create an iterator over the lines of a `BufRead`.
+    pub fn new(reader: R) -> Self {
+        Self { inner: LineReader::new(reader) }
+    }
+
+    /// Set which line terminators split lines.
+    ///
+    /// The default is `LineTerminators::DEFAULT`, which is LF and CRLF.
+    pub fn terminators(self, terminators: LineTerminators) -> Self {
+        Self { inner: self.inner.terminators(terminators) }
+    }
+
+    /// Set resource limits, such as the maximum bytes in one line.
+    pub fn limits(self, limits: Limits) -> Self {
+        Self { inner: self.inner.limits(limits) }
+    }
+
+    /// Get the byte offset of the end of the last line read,
+    /// from the start of the reader, such as to resume reading later.
+    pub fn byte_offset(&self) -> u64 {
+        self.inner.byte_offset()
+    }
+
+    /// Get the underlying reader back.
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+
+}
+
+impl<R: BufRead> Iterator for LinesWithClip<R> {
+    type Item = crate::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.read_next().map(|x| x.map(|line| {
+            String::from(line.content)
+        }))
+    }
+}
+
+/// An iterator over lines; clip each line end, such as `\n` or `\r\n`,
+/// then trim each line of whitespace.
+#[derive(Debug)]
+pub struct LinesWithTrim<R> {
+    inner: LineReader<R>,
+}
+
+impl<R: BufRead> LinesWithTrim<R> {
+
+    /// Create an iterator over the lines of a `BufRead`.
+    pub fn new(reader: R) -> Self {
+        Self { inner: LineReader::new(reader) }
+    }
+
+    /// Set which line terminators split lines.
+    ///
+    /// The default is `LineTerminators::DEFAULT`, which is LF and CRLF.
+    pub fn terminators(self, terminators: LineTerminators) -> Self {
+        Self { inner: self.inner.terminators(terminators) }
+    }
+
+    /// Set resource limits, such as the maximum bytes in one line.
+    pub fn limits(self, limits: Limits) -> Self {
+        Self { inner: self.inner.limits(limits) }
+    }
+
+    /// Get the byte offset of the end of the last line read,
+    /// from the start of the reader, such as to resume reading later.
+    pub fn byte_offset(&self) -> u64 {
+        self.inner.byte_offset()
+    }
+
+    /// Get the underlying reader back.
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+
+}
+
+impl<R: BufRead> Iterator for LinesWithTrim<R> {
+    type Item = crate::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.read_next().map(|x| x.map(|line| {
+            String::from(line.content.trim())
+        }))
+    }
+}
+
+/// An iterator over lines from last to first; keep each line end, such as `\n` or `\r\n`.
+///
+/// This seeks backwards from the end, one block at a time, so memory
+/// is bounded by the longest line, rather than by the whole source.
+#[derive(Debug)]
+pub struct RevLines<R> {
+    inner: ReverseLineReader<R>,
+    /// The path of the file, if known, for errors.
+    path: Option<PathBuf>,
+}
+
+impl<R: Read + Seek> RevLines<R> {
+
+    /// Create an iterator over the lines of a `Read + Seek`, from last to first.
+    pub fn new(reader: R) -> Self {
+        Self { inner: ReverseLineReader::new(reader), path: None }
+    }
+
+    /// Set which line terminators split lines.
+    ///
+    /// The default is `LineTerminators::DEFAULT`, which is LF and CRLF.
+    pub fn terminators(self, terminators: LineTerminators) -> Self {
+        Self { inner: self.inner.terminators(terminators), ..self }
+    }
+
+    /// Set resource limits, such as the maximum bytes in one line.
+    pub fn limits(self, limits: Limits) -> Self {
+        Self { inner: self.inner.limits(limits), ..self }
+    }
+
+    /// Set the path of the file, for errors.
+    pub(crate) fn path(self, path: impl AsRef<Path>) -> Self {
+        Self { path: Some(path.as_ref().to_path_buf()), ..self }
+    }
+
+    /// Get the underlying reader back.
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+
+}
+
+impl<R: Read + Seek> Iterator for RevLines<R> {
+    type Item = crate::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.inner.read_prev()?.map(|(content, ending)| content + ending.as_str());
+        Some(line.map_err(|e| match &self.path {
+            Some(path) => e.with_path(path),
+            None => e,
+        }))
+    }
+}
+
+/// An iterator over lines from last to first; clip each line end, such as `\n` or `\r\n`.
+///
+/// This seeks backwards from the end, one block at a time, so memory
+/// is bounded by the longest line, rather than by the whole source.
+#[derive(Debug)]
+pub struct RevLinesWithClip<R> {
+    inner: ReverseLineReader<R>,
+    /// The path of the file, if known, for errors.
+    path: Option<PathBuf>,
+}
+
+impl<R: Read + Seek> RevLinesWithClip<R> {
+
+    /// Create an iterator over the lines of a `Read + Seek`, from last to first.
+    pub fn new(reader: R) -> Self {
+        Self { inner: ReverseLineReader::new(reader), path: None }
+    }
+
+    /// Set which line terminators split lines.
+    ///
+    /// The default is `LineTerminators::DEFAULT`, which is LF and CRLF.
+    pub fn terminators(self, terminators: LineTerminators) -> Self {
+        Self { inner: self.inner.terminators(terminators), ..self }
+    }
+
+    /// Set resource limits, such as the maximum bytes in one line.
+    pub fn limits(self, limits: Limits) -> Self {
+        Self { inner: self.inner.limits(limits), ..self }
+    }
+
+    /// Set the path of the file, for errors.
+    pub(crate) fn path(self, path: impl AsRef<Path>) -> Self {
+        Self { path: Some(path.as_ref().to_path_buf()), ..self }
+    }
+
+    /// Get the underlying reader back.
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+
+}
+
+impl<R: Read + Seek> Iterator for RevLinesWithClip<R> {
+    type Item = crate::Result<String>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let line = self.inner.read_prev()?.map(|(content, _)| content);
+        Some(line.map_err(|e| match &self.path {
+            Some(path) => e.with_path(path),
+            None => e,
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use crate::error::Error;
+    use crate::error::Limit;
+    use crate::limits::LimitAction;
+
+    #[test]
+    fn lines_with_endings() {
+        let lines = LinesWithEndings::new(Cursor::new("lorem\nipsum\r\n dolor "));
+        assert_eq!(
+            lines.collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec!["lorem\n", "ipsum\r\n", " dolor "]
+        );
+    }
+
+    #[test]
+    fn lines_with_clip() {
+        let lines = LinesWithClip::new(Cursor::new("lorem\nipsum\r\n dolor "));
+        assert_eq!(
+            lines.collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec!["lorem", "ipsum", " dolor "]
+        );
+    }
+
+    #[test]
+    fn lines_with_trim() {
+        let lines = LinesWithTrim::new(Cursor::new("lorem\nipsum\r\n dolor "));
+        assert_eq!(
+            lines.collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec!["lorem", "ipsum", "dolor"]
+        );
+    }
+
+    #[test]
+    fn into_inner_is_positioned_after_last_line() {
+        let mut lines = LinesWithEndings::new(Cursor::new("lorem\nipsum\n"));
+        lines.next();
+        assert_eq!(lines.into_inner().position(), 6);
+    }
+
+    #[test]
+    fn byte_offset() {
+        let mut lines = LinesWithClip::new(Cursor::new("lorem\r\nipsum"));
+        assert_eq!(lines.byte_offset(), 0);
+        lines.next();
+        assert_eq!(lines.byte_offset(), 7);
+        lines.next();
+        assert_eq!(lines.byte_offset(), 12);
+    }
+
+    #[test]
+    fn limits() {
+        let bytes = [vec![b'a'; 10_000_000], b"\nlorem\nipsum\n".to_vec()].concat();
+        let lines = LinesWithTrim::new(Cursor::new(bytes))
+            .limits(Limits::new().max_line_bytes(3, LimitAction::Truncate).max_lines(2, LimitAction::Truncate));
+        assert_eq!(
+            lines.collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec!["aaa", "lor"]
+        );
+    }
+
+    #[test]
+    fn rev_lines() {
+        let lines = RevLines::new(Cursor::new("lorem\nipsum\r\n dolor "));
+        assert_eq!(
+            lines.collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![" dolor ", "ipsum\r\n", "lorem\n"]
+        );
+    }
+
+    #[test]
+    fn rev_lines_with_clip() {
+        let lines = RevLinesWithClip::new(Cursor::new("lorem\nipsum\r\n dolor "));
+        assert_eq!(
+            lines.collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![" dolor ", "ipsum", "lorem"]
+        );
+    }
+
+    #[test]
+    fn rev_lines_with_terminators() {
+        let lines = RevLinesWithClip::new(Cursor::new("lorem\ripsum\u{2028}"))
+            .terminators(LineTerminators::ALL);
+        assert_eq!(
+            lines.collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec!["ipsum", "lorem"]
+        );
+    }
+
+    #[test]
+    fn rev_lines_with_line_bytes_limit() {
+        let mut lines = RevLines::new(Cursor::new("lorem\nipsum dolor\nsit\n"))
+            .limits(Limits::new().max_line_bytes(5, LimitAction::Error));
+        assert_eq!(lines.next().unwrap().unwrap(), "sit\n");
+        let e = lines.next().unwrap().unwrap_err();
+        assert!(matches!(e, Error::LimitExceeded { line: None, limit: Limit::LineBytes(5), .. }));
+        assert!(lines.next().is_none());
+    }
+
+    #[test]
+    fn rev_lines_with_generated_huge_line() {
+        let bytes = [b"lorem\n".to_vec(), vec![b'a'; 10_000_000], b"\r\nipsum\n".to_vec()].concat();
+        let lines = RevLines::new(Cursor::new(bytes))
+            .limits(Limits::new().max_line_bytes(3, LimitAction::Truncate));
+        assert_eq!(
+            lines.collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec!["ips\n", "aaa\r\n", "lor\n"]
+        );
+    }
+
+    #[test]
+    fn rev_lines_with_lines_limit() {
+        let text = "lorem\nipsum\ndolor\n";
+        let lines = RevLinesWithClip::new(Cursor::new(text))
+            .limits(Limits::new().max_lines(2, LimitAction::Truncate));
+        assert_eq!(
+            lines.collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec!["dolor", "ipsum"]
+        );
+        let lines = RevLinesWithClip::new(Cursor::new(text))
+            .limits(Limits::new().max_lines(2, LimitAction::Error));
+        let e = lines.collect::<crate::Result<Vec<String>>>().unwrap_err();
+        assert!(matches!(e, Error::LimitExceeded { limit: Limit::Lines(2), .. }));
+        let lines = RevLinesWithClip::new(Cursor::new(text))
+            .limits(Limits::new().max_lines(3, LimitAction::Error));
+        assert_eq!(lines.count(), 3);
+    }
+
+    #[test]
+    fn rev_lines_with_total_bytes_limit() {
+        let text = "lorem\nipsum\ndolor\n";
+        let lines = RevLinesWithClip::new(Cursor::new(text))
+            .limits(Limits::new().max_total_bytes(12, LimitAction::Truncate));
+        assert_eq!(
+            lines.collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec!["dolor", "ipsum"]
+        );
+        let lines = RevLinesWithClip::new(Cursor::new(text))
+            .limits(Limits::new().max_total_bytes(12, LimitAction::Error));
+        let e = lines.collect::<crate::Result<Vec<String>>>().unwrap_err();
+        assert!(matches!(e, Error::LimitExceeded { limit: Limit::TotalBytes(12), .. }));
+    }
+
+    #[test]
+    fn rev_lines_finds_most_recent_match_in_generated_large_source() {
+        let text = (0..100_000).map(|i| format!("entry {}\n", i)).collect::<String>();
+        let mut lines = RevLinesWithClip::new(Cursor::new(text));
+        let found = lines.by_ref().map(|x| x.unwrap()).find(|x| x.ends_with('7'));
+        assert_eq!(found.as_deref(), Some("entry 99997"));
+        assert!(lines.into_inner().position() > 1_000_000);
+    }
+
+    #[test]
+    fn invalid_utf8_is_an_error() {
+        let mut lines = LinesWithEndings::new(Cursor::new(b"lorem\xff\n".to_vec()));
+        assert!(lines.next().unwrap().is_err());
+    }
+
+    #[test]
+    fn terminators() {
+        let text = "lorem\ripsum\u{85} dolor \u{0C}";
+        let lines = LinesWithEndings::new(Cursor::new(text)).terminators(LineTerminators::ALL);
+        assert_eq!(
+            lines.collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec!["lorem\r", "ipsum\u{85}", " dolor \u{0C}"]
+        );
+        let lines = LinesWithClip::new(Cursor::new(text)).terminators(LineTerminators::ALL);
+        assert_eq!(
+            lines.collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec!["lorem", "ipsum", " dolor "]
+        );
+        let lines = LinesWithTrim::new(Cursor::new(text)).terminators(LineTerminators::ALL);
+        assert_eq!(
+            lines.collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec!["lorem", "ipsum", "dolor"]
+        );
+    }
+
+}
@@ -33,9 +33,286 @@
 //! so you can copy them into your own code if you wish.
 //! 
 //! These functions use buffered readers for efficiency.
-//! 
+//!
+//! The `*OnSelf` traits are implemented for any `BufRead`,
+//! such as `BufReader<File>`, `Cursor<Vec<u8>>`, `StdinLock`,
+//! or a `&mut` reference to any of these. The `*OnRefSelf`
+//! traits are implemented for `File`, `Path`, `str`, `String`, and `OsStr`.
+//!
+//! The `*OnMutSelf` traits borrow a `BufRead` rather than consume it,
+//! and read a bounded number of lines, so the reader can be used again:
+//!
+//! ```rust
+//! use std::io::Cursor;
+//! use read_lines_into::traits::*;
+//!
+//! let mut cursor = Cursor::new("header\n\nbody\n");
+//! let head = cursor.read_lines_into_vec_string_until_blank().unwrap();
+//! let body = cursor.read_lines_into_vec_string().unwrap();
+//! ```
+//!
+//! For other line terminators, or limits, use `ReadLinesOptions::take`
+//! or `ReadLinesOptions::until_blank` with a `&mut` reader.
+//!
+//! The `path` module also has free functions that accept anything
+//! that implements `AsRef<Path>`, such as `PathBuf`, `&str`, or `String`:
+//!
+//! ```rust
+//! let strings = read_lines_into::path::read_lines_into_vec_string("example.txt").unwrap();
+//! ```
+//!
+//! To read lines into any other collection, such as `HashSet<String>`
+//! or `VecDeque<String>`, use `read_lines_into`; to append lines to an
+//! existing collection, use `read_lines_extend`. Both clip each line
+//! end, so that `lorem\n` and `lorem\r\n` are the same line:
+//!
+//! ```rust
+//! use std::collections::HashSet;
+//! use read_lines_into::traits::*;
+//!
+//! let mut set = "example.txt".read_lines_into::<HashSet<String>>().unwrap();
+//! "example-with-crlf.txt".read_lines_extend(&mut set).unwrap();
+//! assert_eq!(set, HashSet::from([String::from("lorem"), String::from("ipsum")]));
+//! ```
+//!
+//! To parse each line into a typed value, use `read_lines_into_vec_parsed`,
+//! which clips and trims each line, then uses `FromStr`. A parse error
+//! has the path, the line number, the line, and the `FromStr` error:
+//!
+//! ```rust
+//! use read_lines_into::traits::*;
+//!
+//! let e = "example.txt".read_lines_into_vec_parsed::<u64>().unwrap_err();
+//! assert_eq!(e.to_string(), "example.txt:1: cannot parse \"lorem\": invalid digit found in string");
+//! ```
+//!
+//! To read only the last lines of a huge file, such as a log, use
+//! `read_last_lines_into_vec_string`, which seeks backwards from the end,
+//! and has `_with_clip` and `_with_trim`. This works for `File`, `Path`,
+//! and any `BufRead` that is also `Seek`, such as `BufReader<File>`.
+//! For other terminators, or limits, use `ReadLinesOptions::read_path_last_into`:
+//!
+//! ```rust
+//! use read_lines_into::traits::*;
+//!
+//! let strings = "example.txt".read_last_lines_into_vec_string_with_clip(1).unwrap();
+//! assert_eq!(strings, vec!["ipsum"]);
+//! ```
+//!
+//! To keep reading a file as it grows, like `tail -F`, use `follow`,
+//! which polls for appended lines, and reopens the path when the file
+//! is rotated or truncated:
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use read_lines_into::traits::*;
+//!
+//! for line in "example.txt".follow().idle_timeout(Duration::from_millis(50)) {
+//!     let string: String = line.unwrap();
+//! }
+//! ```
+//!
+//! To ingest an append-only file in batches, and restart where the
+//! last batch stopped, use `read_lines_from_offset`, which returns a
+//! `Checkpoint` at the end of the last complete line. A checkpoint
+//! saves as text, and is checked against the file's device, inode,
+//! and length, so a replaced or truncated file is an error. For other
+//! terminators, or limits, use `ReadLinesOptions::read_path_from_offset`:
+//!
+//! ```rust
+//! use read_lines_into::Checkpoint;
+//! use read_lines_into::traits::*;
+//!
+//! let (strings, checkpoint) = "example.txt".read_lines_from_offset(Checkpoint::new()).unwrap();
+//! let text = checkpoint.to_string();
+//! let (strings, checkpoint) = "example.txt".read_lines_from_offset(text.parse().unwrap()).unwrap();
+//! ```
+//!
+//! ## Errors
+//!
+//! Every read returns `read_lines_into::Result`, whose `Error` has
+//! context, such as the path, the line number, and the byte offset,
+//! and converts to and from `std::io::Error`:
+//!
+//! ```rust
+//! use read_lines_into::traits::*;
+//!
+//! let e = "example-with-latin1.txt".read_lines_into_string().unwrap_err();
+//! assert_eq!(e.to_string(), "example-with-latin1.txt:2: stream did not contain valid UTF-8 at byte offset 15");
+//! ```
+//!
+//! For text that is mostly UTF-8, such as logs with the odd Latin-1 byte,
+//! use `ReadLinesOptions::lossy`, which replaces invalid UTF-8 with U+FFFD.
+//! For raw bytes, use `read_lines_into_vec_bytes`, which has `_with_clip`
+//! and `_with_trim`, or `ReadLinesOptions::read_bytes_into`, which also
+//! takes terminators and limits:
+//!
+//! ```rust
+//! use read_lines_into::options::*;
+//! use read_lines_into::traits::*;
+//!
+//! let strings: Vec<String> = ReadLinesOptions::new().lossy(true).read_path_into("example-with-latin1.txt").unwrap();
+//! let lines = "example-with-latin1.txt".read_lines_into_vec_bytes_with_trim().unwrap();
+//! ```
+//!
+//! For files from tools that write a byte order mark (BOM), or UTF-16,
+//! use `ReadLinesOptions::decode`, which strips any BOM, and decodes
+//! UTF-16 into UTF-8. To know which encoding was found, use `DecodingReader`:
+//!
+//! ```rust
+//! use std::fs::File;
+//! use std::io::BufReader;
+//! use read_lines_into::{DecodingReader, Encoding};
+//! use read_lines_into::options::*;
+//! use read_lines_into::traits::*;
+//!
+//! let strings: Vec<String> = ReadLinesOptions::new().decode(true).read_path_into("example-with-utf8-bom.txt").unwrap();
+//! assert_eq!(strings, vec!["lorem\n", "ipsum\n"]);
+//!
+//! let file = File::open("example-with-utf16be.txt").unwrap();
+//! let mut reader = DecodingReader::new(BufReader::new(file));
+//! let strings = (&mut reader).read_lines_into_vec_string().unwrap();
+//! assert_eq!(reader.encoding(), Some(Encoding::Utf16Be));
+//! ```
+//!
+//! ## Options
+//!
+//! The trait methods that read a whole source into strings, such as
+//! `read_lines_into_vec_string_with_trim`, are thin wrappers over
+//! `ReadLinesOptions`, which can combine any way to handle line endings
+//! with any way to handle whitespace, and can read into any collection:
+//!
+//! ```rust
+//! use read_lines_into::options::*;
+//!
+//! let options = ReadLinesOptions::new()
+//!     .endings(Endings::Normalize(read_lines_into::NewlineStyle::Lf))
+//!     .whitespace(Whitespace::TrimEnd)
+//!     .skip_blank(true);
+//! let string: String = options.read_path_into("example-with-crlf.txt").unwrap();
+//! ```
+//!
+//! The `_with_trim` methods clip line endings, so lines run together.
+//! To trim lines yet keep them apart, keep or normalize line endings
+//! with `ReadLinesOptions::endings`, or join lines with a separator:
+//!
+//! ```rust
+//! use read_lines_into::options::*;
+//!
+//! let options = ReadLinesOptions::new().endings(Endings::Clip).whitespace(Whitespace::Trim);
+//! let string = options.read_path_joined("example-with-indent.txt", "\n").unwrap();
+//! assert_eq!(string, "lorem\nipsum");
+//! ```
+//!
+//! To trim only one side, use `Whitespace::TrimStart` or
+//! `Whitespace::TrimEnd`. To trim only some characters, use
+//! `ReadLinesOptions::trim_chars` to choose ASCII whitespace,
+//! Unicode whitespace, a list of characters, or a predicate:
+//!
+//! ```rust
+//! use read_lines_into::options::*;
+//!
+//! let strings: Vec<String> = ReadLinesOptions::new()
+//!     .endings(Endings::Clip)
+//!     .whitespace(Whitespace::TrimStart)
+//!     .trim_chars(TrimChars::Matches(vec![' ']))
+//!     .read_path_into("example-with-indent.txt")
+//!     .unwrap();
+//! assert_eq!(strings, vec!["lorem", "ipsum"]);
+//! ```
+//!
+//! To remove only the indentation that all lines share, and keep
+//! nested indentation, use `_with_dedent`, or `ReadLinesOptions::dedent`
+//! with other options, which is like Python's `textwrap.dedent`:
+//!
+//! ```rust
+//! use read_lines_into::options::*;
+//! use read_lines_into::traits::*;
+//!
+//! let string = "example-with-indent.txt".read_lines_into_string_with_dedent().unwrap();
+//! assert_eq!(string, "lorem\nipsum\n");
+//! let string: String = ReadLinesOptions::new()
+//!     .dedent(true)
+//!     .comment_prefixes(&["#"])
+//!     .read_path_into("example-with-indent.txt")
+//!     .unwrap();
+//! assert_eq!(string, "lorem\nipsum\n");
+//! ```
+//!
+//! To expand tabs to spaces for fixed-width output, use
+//! `ReadLinesOptions::expand_tabs`, which works with dedent and trim.
+//!
+//! To skip blank lines and comment lines, such as in config files:
+//!
+//! ```rust
+//! use read_lines_into::options::*;
+//!
+//! let strings: Vec<String> = ReadLinesOptions::new()
+//!     .endings(Endings::Clip)
+//!     .whitespace(Whitespace::Trim)
+//!     .skip_blank(true)
+//!     .comment_prefixes(&["#", "//"])
+//!     .inline_comments(true)
+//!     .read_path_into("example.txt")
+//!     .unwrap();
+//! ```
+//!
+//! To read untrusted or huge sources, such as uploads, with bounded
+//! memory, use `ReadLinesOptions::limits`, which can limit the bytes
+//! in one line, the number of lines, and the total bytes. Each limit
+//! either returns `Error::LimitExceeded`, or truncates:
+//!
+//! ```rust
+//! use read_lines_into::{LimitAction, Limits};
+//! use read_lines_into::options::*;
+//!
+//! let strings: Vec<String> = ReadLinesOptions::new()
+//!     .limits(Limits::new()
+//!         .max_line_bytes(64 * 1024, LimitAction::Error)
+//!         .max_lines(1_000_000, LimitAction::Truncate))
+//!     .read_path_into("example.txt")
+//!     .unwrap();
+//! ```
+//!
+//! ## Line records
+//!
+//! To know where each line came from, read lines into `Vec<Line>`.
+//! Each `Line` has its line number, byte offset, content, and line ending,
+//! so clipping and trimming are views over one record:
+//!
+//! ```rust
+//! use read_lines_into::traits::*;
+//!
+//! for line in "example.txt".read_lines_into_vec_line().unwrap() {
+//!     println!("example.txt:{}: {}", line.number, line.trimmed());
+//! }
+//! ```
+//!
+//! For other line terminators, or limits, use `ReadLinesOptions::read_lines`.
+//!
 //! If you're reading very large files, then you may prefer
-//! to write your own code to process each line as it's read.
+//! the lazy iterators `lines_with_endings`, `lines_with_clip`,
+//! and `lines_with_trim`, which process each line as it's read:
+//!
+//! ```rust
+//! # use std::path::Path;
+//! # use read_lines_into::traits::*;
+//! for line in Path::new("example.txt").lines_with_endings().unwrap() {
+//!     let string: String = line.unwrap();
+//! }
+//! ```
+//!
+//! To search from the end, such as for the most recent matching
+//! entry in a large log, use `rev_lines` or `rev_lines_with_clip`,
+//! which yield lines from last to first, by seeking backwards:
+//!
+//! ```rust
+//! # use read_lines_into::traits::*;
+//! let last_match = "example.txt".rev_lines_with_clip().unwrap()
+//!     .map(|line| line.unwrap())
+//!     .find(|line| line.starts_with('l'));
+//! assert_eq!(last_match.as_deref(), Some("lorem"));
+//! ```
 //! 
 //! ## Line endings using LF and CRLF
 //! 
@@ -43,6 +320,14 @@
 //! 
 //! Windows systems typically end text lines with `\r` CARRIAGE RETURN (CR)
 //! then `\n` LINE FEED (LF).
+//!
+//! By default, lines end with LF or CRLF, which is the same as Rust
+//! `BufRead::lines()`. To also split lines at a lone CR, or at the
+//! Unicode line terminators NEL, LS, PS, VT, and FF, see `LineTerminators`.
+//!
+//! To lint a file for mixed line endings, use `read_line_ending_report`,
+//! or use `ReadLinesOptions::strict_endings` to fail on the first line
+//! whose line ending differs from the dominant line ending.
 //! 
 //! ## Tests
 //! 
@@ -52,6 +337,21 @@
 //!   * example-with-crlf.txt has lines that end wit CRLF.
 //!   * example-with-indent.txt has lines with leading spaces.
 //!
+//! More test files have lines that end with other line terminators:
+//!
+//!   * example-with-cr.txt has lines that end with CR.
+//!   * example-with-nel.txt has lines that end with NEL.
+//!   * example-with-ls.txt has lines that end with LS.
+//!   * example-with-ps.txt has lines that end with PS.
+//!   * example-with-vt.txt has lines that end with VT.
+//!   * example-with-ff.txt has lines that end with FF.
+//!   * example-with-mixed-endings.txt has lines that end with LF or CRLF.
+//!   * example-with-latin1.txt has a line that is not valid UTF-8.
+//!   * example-with-utf8-bom.txt starts with a UTF-8 byte order mark.
+//!   * example-with-utf16le.txt is UTF-16LE with a byte order mark.
+//!   * example-with-utf16be.txt is UTF-16BE with a byte order mark.
+//!   * example-without-final-newline.txt has a last line without a line ending.
+//!
 //! ## Tracking
 //! 
 //! * Project: read-lines-into-rust-crate
@@ -68,13 +368,69 @@
 // use std::path::Path;
 
 pub mod traits;
+pub mod checkpoint;
+pub mod error;
+pub mod encoding;
+pub mod follow;
+pub mod decoding_reader;
+pub mod limits;
+pub mod line_ending;
+pub mod line_ending_report;
+pub mod options;
+pub mod parse_lines_error;
+pub mod iter;
+pub mod line;
+mod file_identity;
+mod line_reader;
+mod reverse_line_reader;
+#[cfg(test)]
+mod test_util;
+pub mod line_terminators;
+pub use checkpoint::Checkpoint;
+pub use decoding_reader::DecodingReader;
+pub use encoding::Encoding;
+pub use follow::Follow;
+pub use error::Error;
+pub use error::Result;
+pub use limits::LimitAction;
+pub use limits::Limits;
+pub use line_ending::LineEnding;
+pub use line_ending::NewlineStyle;
+pub use line::Line;
+pub use line_terminators::LineTerminators;
+pub use options::ReadLinesOptions;
+pub use parse_lines_error::ParseLinesError;
 pub mod buf_reader {
     pub mod read_lines_into_string;
     pub mod read_lines_into_string_with_clip;
     pub mod read_lines_into_string_with_trim;
+    pub mod read_lines_into_string_take;
+    pub mod read_lines_into_string_until_blank;
     pub mod read_lines_into_vec_string;
     pub mod read_lines_into_vec_string_with_clip;
     pub mod read_lines_into_vec_string_with_trim;
+    pub mod read_lines_into_vec_string_take;
+    pub mod read_lines_into_vec_string_until_blank;
+    pub mod read_lines_into_with_options;
+    pub mod lines_with_endings;
+    pub mod lines_with_clip;
+    pub mod lines_with_trim;
+    pub mod read_lines_into_vec_line;
+    pub mod read_lines_into_string_with_normalized_endings;
+    pub mod read_line_ending_report;
+    pub mod read_lines_into_string_with_dedent;
+    pub mod read_lines_into_vec_string_with_dedent;
+    pub mod read_lines_into;
+    pub mod read_lines_extend;
+    pub mod read_lines_into_vec_parsed;
+    pub mod read_lines_into_vec_bytes;
+    pub mod read_lines_into_vec_bytes_with_clip;
+    pub mod read_lines_into_vec_bytes_with_trim;
+    pub mod read_last_lines_into_vec_string;
+    pub mod read_last_lines_into_vec_string_with_clip;
+    pub mod read_last_lines_into_vec_string_with_trim;
+    pub mod rev_lines;
+    pub mod rev_lines_with_clip;
 }
 pub mod file {
     pub mod read_lines_into_string;
@@ -83,6 +439,27 @@ pub mod file {
     pub mod read_lines_into_vec_string;
     pub mod read_lines_into_vec_string_with_clip;
     pub mod read_lines_into_vec_string_with_trim;
+    pub mod read_lines_into_with_options;
+    pub mod lines_with_endings;
+    pub mod lines_with_clip;
+    pub mod lines_with_trim;
+    pub mod read_lines_into_vec_line;
+    pub mod read_lines_into_string_with_normalized_endings;
+    pub mod read_line_ending_report;
+    pub mod read_lines_into_string_with_dedent;
+    pub mod read_lines_into_vec_string_with_dedent;
+    pub mod read_lines_into;
+    pub mod read_lines_extend;
+    pub mod read_lines_into_vec_parsed;
+    pub mod read_lines_into_vec_bytes;
+    pub mod read_lines_into_vec_bytes_with_clip;
+    pub mod read_lines_into_vec_bytes_with_trim;
+    pub mod read_last_lines_into_vec_string;
+    pub mod read_last_lines_into_vec_string_with_clip;
+    pub mod read_last_lines_into_vec_string_with_trim;
+    pub mod read_lines_from_offset;
+    pub mod rev_lines;
+    pub mod rev_lines_with_clip;
 }
 pub mod path {
     pub mod read_lines_into_string;
@@ -91,4 +468,54 @@ pub mod path {
     pub mod read_lines_into_vec_string;
     pub mod read_lines_into_vec_string_with_clip;
     pub mod read_lines_into_vec_string_with_trim;
+    pub mod read_lines_into_with_options;
+    pub mod lines_with_endings;
+    pub mod lines_with_clip;
+    pub mod lines_with_trim;
+    pub mod read_lines_into_vec_line;
+    pub mod read_lines_into_string_with_normalized_endings;
+    pub mod read_line_ending_report;
+    pub mod read_lines_into_string_with_dedent;
+    pub mod read_lines_into_vec_string_with_dedent;
+    pub mod read_lines_into;
+    pub mod read_lines_extend;
+    pub mod read_lines_into_vec_parsed;
+    pub mod read_lines_into_vec_bytes;
+    pub mod read_lines_into_vec_bytes_with_clip;
+    pub mod read_lines_into_vec_bytes_with_trim;
+    pub mod read_last_lines_into_vec_string;
+    pub mod read_last_lines_into_vec_string_with_clip;
+    pub mod read_last_lines_into_vec_string_with_trim;
+    pub mod follow;
+    pub mod read_lines_from_offset;
+    pub mod rev_lines;
+    pub mod rev_lines_with_clip;
+    pub use self::read_lines_into_string::read_lines_into_string;
+    pub use self::read_lines_into_string_with_clip::read_lines_into_string_with_clip;
+    pub use self::read_lines_into_string_with_trim::read_lines_into_string_with_trim;
+    pub use self::read_lines_into_vec_string::read_lines_into_vec_string;
+    pub use self::read_lines_into_vec_string_with_clip::read_lines_into_vec_string_with_clip;
+    pub use self::read_lines_into_vec_string_with_trim::read_lines_into_vec_string_with_trim;
+    pub use self::read_lines_into_with_options::read_lines_into_with_options;
+    pub use self::lines_with_endings::lines_with_endings;
+    pub use self::lines_with_clip::lines_with_clip;
+    pub use self::lines_with_trim::lines_with_trim;
+    pub use self::read_lines_into_vec_line::read_lines_into_vec_line;
+    pub use self::read_lines_into_string_with_normalized_endings::read_lines_into_string_with_normalized_endings;
+    pub use self::read_line_ending_report::read_line_ending_report;
+    pub use self::read_lines_into_string_with_dedent::read_lines_into_string_with_dedent;
+    pub use self::read_lines_into_vec_string_with_dedent::read_lines_into_vec_string_with_dedent;
+    pub use self::read_lines_into::read_lines_into;
+    pub use self::read_lines_extend::read_lines_extend;
+    pub use self::read_lines_into_vec_parsed::read_lines_into_vec_parsed;
+    pub use self::read_lines_into_vec_bytes::read_lines_into_vec_bytes;
+    pub use self::read_lines_into_vec_bytes_with_clip::read_lines_into_vec_bytes_with_clip;
+    pub use self::read_lines_into_vec_bytes_with_trim::read_lines_into_vec_bytes_with_trim;
+    pub use self::read_last_lines_into_vec_string::read_last_lines_into_vec_string;
+    pub use self::read_last_lines_into_vec_string_with_clip::read_last_lines_into_vec_string_with_clip;
+    pub use self::read_last_lines_into_vec_string_with_trim::read_last_lines_into_vec_string_with_trim;
+    pub use self::follow::follow;
+    pub use self::read_lines_from_offset::read_lines_from_offset;
+    pub use self::rev_lines::rev_lines;
+    pub use self::rev_lines_with_clip::rev_lines_with_clip;
 }
@@ -0,0 +1,77 @@
+//! Resource limits, so that a huge or hostile source can't use
+//! unbounded memory, such as one line without any line ending.
+//!
+//! ```
+//! use std::io::Cursor;
+//! use read_lines_into::limits::*;
+//! use read_lines_into::options::*;
+//!
+//! let options = ReadLinesOptions::new().limits(Limits::new()
+//!     .max_line_bytes(5, LimitAction::Truncate)
+//!     .max_lines(2, LimitAction::Error));
+//! let strings: Vec<String> = options.read_into(Cursor::new("lorem ipsum\ndolor\n")).unwrap();
+//! assert_eq!(strings, vec!["lorem\n", "dolor\n"]);
+//! ```
+
+pub use crate::error::Limit;
+
+/// What to do when a limit is exceeded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LimitAction {
+    /// Return `Error::LimitExceeded`.
+    #[default]
+    Error,
+    /// Truncate: for a line, keep the start of the line;
+    /// for lines or bytes, stop before the line that exceeds the limit.
+    Truncate,
+}
+
+/// Resource limits. The default has no limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Limits {
+    pub(crate) max_line_bytes: Option<(usize, LimitAction)>,
+    pub(crate) max_lines: Option<(usize, LimitAction)>,
+    pub(crate) max_total_bytes: Option<(u64, LimitAction)>,
+}
+
+impl Limits {
+
+    /// Create limits, with no limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum number of bytes in one line, not including
+    /// the line ending.
+    ///
+    /// A truncated line keeps its line ending, and is truncated
+    /// at a UTF-8 character boundary.
+    pub fn max_line_bytes(mut self, max: usize, action: LimitAction) -> Self {
+        self.max_line_bytes = Some((max, action));
+        self
+    }
+
+    /// Set the maximum number of lines.
+    pub fn max_lines(mut self, max: usize, action: LimitAction) -> Self {
+        self.max_lines = Some((max, action));
+        self
+    }
+
+    /// Set the maximum number of bytes to read from the source,
+    /// including line endings.
+    pub fn max_total_bytes(mut self, max: u64, action: LimitAction) -> Self {
+        self.max_total_bytes = Some((max, action));
+        self
+    }
+
+}
+
+/// Find where to truncate bytes that are longer than `max`,
+/// so they keep at most `max` bytes, at a UTF-8 character boundary.
+pub(crate) fn truncate_point(bytes: &[u8], max: usize) -> usize {
+    let mut cut = max;
+    while cut > 0 && bytes[cut] & 0xC0 == 0x80 {
+        cut -= 1;
+    }
+    cut
+}
@@ -0,0 +1,234 @@
+//! A line record, which knows where the line came from.
+//!
+//! ## Round trip
+//!
+//! Line records are lossless: for any UTF-8 source, writing the
+//! line records back out gives the exact original bytes, including
+//! every `\n`, `\r\n`, lone `\r`, and a missing final newline.
+//!
+//! ```
+//! use read_lines_into::line::to_original_bytes;
+//! use read_lines_into::traits::*;
+//!
+//! let lines = "example-with-crlf.txt".read_lines_into_vec_line().unwrap();
+//! assert_eq!(to_original_bytes(&lines), std::fs::read("example-with-crlf.txt").unwrap());
+//! ```
+
+use std::fmt;
+use std::io::Write;
+use crate::line_ending::LineEnding;
+
+/// A line record: the line's content, its line ending,
+/// and where the line starts in the source.
+///
+/// Clipping and trimming are views over the same record,
+/// so there is no need to read the source more than once.
+///
+/// ```
+/// use read_lines_into::traits::*;
+///
+/// let lines = "example-with-crlf.txt".read_lines_into_vec_line().unwrap();
+/// let line = &lines[1];
+/// assert_eq!(format!("example-with-crlf.txt:{}", line.number), "example-with-crlf.txt:2");
+/// assert_eq!(line.byte_offset, 7);
+/// assert_eq!(line.clipped(), "ipsum");
+/// assert_eq!(line.to_string(), "ipsum\r\n");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Line {
+    /// The line number, starting at 1.
+    pub number: usize,
+    /// The byte offset of the start of the line in the source, starting at 0,
+    /// or in the decoded text, with `ReadLinesOptions::decode`.
+    pub byte_offset: u64,
+    /// The line content, without its line ending.
+    pub content: String,
+    /// The line ending.
+    pub ending: LineEnding,
+}
+
+impl Line {
+
+    /// Get the line content; clip the line end.
+    pub fn clipped(&self) -> &str {
+        &self.content
+    }
+
+    /// Get the line content; clip the line end, then trim whitespace.
+    pub fn trimmed(&self) -> &str {
+        self.content.trim()
+    }
+
+    /// Get the line content with its line ending, as in the source.
+    pub fn with_ending(&self) -> String {
+        self.to_string()
+    }
+
+    /// Get the number of bytes of the line in the source,
+    /// including the line ending.
+    pub fn byte_len(&self) -> usize {
+        self.content.len() + self.ending.as_str().len()
+    }
+
+}
+
+/// Display the line content with its line ending, as in the source.
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.content)?;
+        f.write_str(self.ending.as_str())
+    }
+}
+
+/// Write line records, each with its line ending, as in the source.
+///
+/// This is the counterpart of `read_lines_into_vec_line`,
+/// and writes the exact original bytes; see the round trip above.
+///
+/// ```
+/// use read_lines_into::line::write_lines;
+/// use read_lines_into::traits::*;
+///
+/// let lines = "example.txt".read_lines_into_vec_line().unwrap();
+/// let mut bytes: Vec<u8> = Vec::new();
+/// write_lines(&mut bytes, &lines).unwrap();
+/// assert_eq!(bytes, b"lorem\nipsum\n");
+/// ```
+///
+/// Any error will return immediately.
+///
+pub fn write_lines<W: Write>(mut writer: W, lines: &[Line]) -> ::std::io::Result<()> {
+    for line in lines {
+        writer.write_all(line.content.as_bytes())?;
+        writer.write_all(line.ending.as_str().as_bytes())?;
+    }
+    Ok(())
+}
+
+/// Convert line records, each with its line ending, to the original bytes.
+///
+/// This is the counterpart of `read_lines_into_vec_line`;
+/// see the round trip above.
+///
+pub fn to_original_bytes(lines: &[Line]) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(lines.iter().map(Line::byte_len).sum());
+    for line in lines {
+        bytes.extend_from_slice(line.content.as_bytes());
+        bytes.extend_from_slice(line.ending.as_str().as_bytes());
+    }
+    bytes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use proptest::prelude::*;
+    use crate::traits::*;
+
+    fn sut() -> Line {
+        Line {
+            number: 2,
+            byte_offset: 7,
+            content: String::from("  ipsum  "),
+            ending: LineEnding::CrLf,
+        }
+    }
+
+    #[test]
+    fn views() {
+        let line = sut();
+        assert_eq!(line.clipped(), "  ipsum  ");
+        assert_eq!(line.trimmed(), "ipsum");
+        assert_eq!(line.with_ending(), "  ipsum  \r\n");
+        assert_eq!(line.byte_len(), 11);
+    }
+
+    #[test]
+    fn round_trip_with_fixtures() {
+        for path in ["example.txt", "example-with-crlf.txt", "example-with-indent.txt"] {
+            let lines = path.read_lines_into_vec_line().unwrap();
+            assert_eq!(to_original_bytes(&lines), ::std::fs::read(path).unwrap());
+        }
+    }
+
+    #[test]
+    fn round_trip_with_lone_cr_and_missing_final_newline() {
+        let text = "lorem\ripsum\r\n\r\rdolor\n\nsit";
+        let lines = Cursor::new(text).read_lines_into_vec_line().unwrap();
+        let mut bytes: Vec<u8> = Vec::new();
+        write_lines(&mut bytes, &lines).unwrap();
+        assert_eq!(bytes, text.as_bytes());
+        assert_eq!(lines.last().unwrap().ending, LineEnding::None);
+    }
+
+    /// Text mixing arbitrary characters with LF, CRLF, and lone CR.
+    fn text() -> impl Strategy<Value = String> {
+        prop::collection::vec(
+            prop_oneof![
+                Just(String::from("\n")),
+                Just(String::from("\r\n")),
+                Just(String::from("\r")),
+                any::<String>(),
+            ],
+            0..16,
+        ).prop_map(|parts| parts.concat())
+    }
+
+    /// Bytes mixing mostly valid UTF-8, and sometimes arbitrary bytes,
+    /// with LF, CRLF, and lone CR.
+    fn bytes() -> impl Strategy<Value = Vec<u8>> {
+        prop::collection::vec(
+            prop_oneof![
+                3 => Just(b"\n".to_vec()),
+                3 => Just(b"\r\n".to_vec()),
+                3 => Just(b"\r".to_vec()),
+                8 => any::<String>().prop_map(String::into_bytes),
+                1 => any::<Vec<u8>>(),
+            ],
+            0..16,
+        ).prop_map(|parts| parts.concat())
+    }
+
+    proptest! {
+
+        #[test]
+        fn round_trip_with_any_text(text in text()) {
+            let lines = Cursor::new(text.as_bytes()).read_lines_into_vec_line().unwrap();
+            prop_assert_eq!(to_original_bytes(&lines), text.as_bytes());
+            prop_assert_eq!(Cursor::new(text.as_bytes()).read_lines_into_string().unwrap(), text.clone());
+            prop_assert_eq!(Cursor::new(text.as_bytes()).read_lines_into_vec_string().unwrap().concat(), text);
+        }
+
+        #[test]
+        fn round_trip_with_any_text_and_all_terminators(text in text()) {
+            let lines = crate::iter::LinesWithEndings::new(Cursor::new(text.as_bytes()))
+                .terminators(crate::line_terminators::LineTerminators::ALL)
+                .collect::<crate::Result<Vec<String>>>()
+                .unwrap();
+            prop_assert_eq!(lines.concat(), text);
+        }
+
+        #[test]
+        fn round_trip_with_any_bytes(bytes in bytes()) {
+            prop_assert_eq!(Cursor::new(&bytes).read_lines_into_vec_bytes().unwrap().concat(), bytes.clone());
+            match Cursor::new(&bytes).read_lines_into_vec_line() {
+                Ok(lines) => prop_assert_eq!(to_original_bytes(&lines), bytes),
+                Err(_) => prop_assert!(::std::str::from_utf8(&bytes).is_err()),
+            }
+        }
+
+        #[test]
+        fn byte_offsets_and_numbers_agree(text in text()) {
+            let lines = Cursor::new(text.as_bytes()).read_lines_into_vec_line().unwrap();
+            let mut byte_offset = 0;
+            for (i, line) in lines.iter().enumerate() {
+                prop_assert_eq!(line.number, i + 1);
+                prop_assert_eq!(line.byte_offset, byte_offset);
+                byte_offset += line.byte_len() as u64;
+            }
+        }
+
+    }
+
+}
@@ -0,0 +1,139 @@
+//! Line endings, such as `\n` LINE FEED (LF) and `\r\n` CARRIAGE RETURN
+//! then LINE FEED (CRLF).
+//!
+//! Which line endings split lines is up to `LineTerminators`.
+
+/// A line ending.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineEnding {
+    /// `\n` LINE FEED (LF), as used by Unix systems.
+    Lf,
+    /// `\r\n` CARRIAGE RETURN then LINE FEED (CRLF), as used by Windows systems.
+    CrLf,
+    /// `\r` CARRIAGE RETURN (CR), as used by classic Mac OS systems.
+    Cr,
+    /// `\u{85}` NEXT LINE (NEL), as used by EBCDIC systems.
+    Nel,
+    /// `\u{2028}` LINE SEPARATOR (LS).
+    Ls,
+    /// `\u{2029}` PARAGRAPH SEPARATOR (PS).
+    Ps,
+    /// `\u{0B}` LINE TABULATION (VT).
+    Vt,
+    /// `\u{0C}` FORM FEED (FF).
+    Ff,
+    /// No line ending, such as a last line without a final newline.
+    None,
+}
+
+impl LineEnding {
+
+    /// Get the line ending as a string slice.
+    /// 
+    /// ```
+    /// use read_lines_into::LineEnding;
+    /// 
+    /// assert_eq!(LineEnding::Lf.as_str(), "\n");
+    /// assert_eq!(LineEnding::CrLf.as_str(), "\r\n");
+    /// assert_eq!(LineEnding::None.as_str(), "");
+    /// ```
+    /// 
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "\n",
+            LineEnding::CrLf => "\r\n",
+            LineEnding::Cr => "\r",
+            LineEnding::Nel => "\u{85}",
+            LineEnding::Ls => "\u{2028}",
+            LineEnding::Ps => "\u{2029}",
+            LineEnding::Vt => "\u{0B}",
+            LineEnding::Ff => "\u{0C}",
+            LineEnding::None => "",
+        }
+    }
+
+    /// Get the line ending's short name, such as for error messages.
+    /// 
+    /// ```
+    /// use read_lines_into::LineEnding;
+    /// 
+    /// assert_eq!(LineEnding::Lf.name(), "LF");
+    /// assert_eq!(LineEnding::CrLf.name(), "CRLF");
+    /// ```
+    /// 
+    pub fn name(&self) -> &'static str {
+        match self {
+            LineEnding::Lf => "LF",
+            LineEnding::CrLf => "CRLF",
+            LineEnding::Cr => "CR",
+            LineEnding::Nel => "NEL",
+            LineEnding::Ls => "LS",
+            LineEnding::Ps => "PS",
+            LineEnding::Vt => "VT",
+            LineEnding::Ff => "FF",
+            LineEnding::None => "no line ending",
+        }
+    }
+
+}
+
+/// A newline style to normalize line endings to, which is
+/// either LF or CRLF.
+///
+/// Unlike `LineEnding`, this can't be no line ending, nor a rarer
+/// line ending such as CR, so normalizing always gives a newline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NewlineStyle {
+    /// `\n` LINE FEED (LF), as used by Unix systems.
+    Lf,
+    /// `\r\n` CARRIAGE RETURN then LINE FEED (CRLF), as used by Windows systems.
+    CrLf,
+}
+
+impl NewlineStyle {
+
+    /// Get the newline as a string slice.
+    /// 
+    /// ```
+    /// use read_lines_into::NewlineStyle;
+    /// 
+    /// assert_eq!(NewlineStyle::Lf.as_str(), "\n");
+    /// assert_eq!(NewlineStyle::CrLf.as_str(), "\r\n");
+    /// ```
+    /// 
+    pub fn as_str(&self) -> &'static str {
+        LineEnding::from(*self).as_str()
+    }
+
+}
+
+impl From<NewlineStyle> for LineEnding {
+    fn from(style: NewlineStyle) -> Self {
+        match style {
+            NewlineStyle::Lf => LineEnding::Lf,
+            NewlineStyle::CrLf => LineEnding::CrLf,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn as_str() {
+        assert_eq!(LineEnding::Lf.as_str(), "\n");
+        assert_eq!(LineEnding::CrLf.as_str(), "\r\n");
+        assert_eq!(LineEnding::Cr.as_str(), "\r");
+        assert_eq!(LineEnding::Nel.as_str(), "\u{85}");
+        assert_eq!(LineEnding::None.as_str(), "");
+    }
+
+    #[test]
+    fn newline_style() {
+        assert_eq!(NewlineStyle::Lf.as_str(), "\n");
+        assert_eq!(NewlineStyle::CrLf.as_str(), "\r\n");
+        assert_eq!(LineEnding::from(NewlineStyle::CrLf), LineEnding::CrLf);
+    }
+
+}
@@ -0,0 +1,140 @@
+//! Line ending detection, such as to lint files for mixed line endings.
+
+use crate::line_ending::LineEnding;
+
+/// A report of the line endings in a source.
+///
+/// ```
+/// use read_lines_into::LineEnding;
+/// use read_lines_into::traits::*;
+///
+/// let report = "example-with-mixed-endings.txt".read_line_ending_report().unwrap();
+/// assert_eq!(report.lf, 2);
+/// assert_eq!(report.crlf, 1);
+/// assert_eq!(report.dominant, Some(LineEnding::Lf));
+/// assert!(report.is_mixed);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LineEndingReport {
+    /// The number of lines that end with `\n` LF.
+    pub lf: usize,
+    /// The number of lines that end with `\r\n` CRLF.
+    pub crlf: usize,
+    /// The number of lines that end with a lone `\r` CR.
+    pub cr: usize,
+    /// The number of lines that end with `\u{85}` NEL.
+    pub nel: usize,
+    /// The number of lines that end with `\u{2028}` LS.
+    pub ls: usize,
+    /// The number of lines that end with `\u{2029}` PS.
+    pub ps: usize,
+    /// The number of lines that end with `\u{0B}` VT.
+    pub vt: usize,
+    /// The number of lines that end with `\u{0C}` FF.
+    pub ff: usize,
+    /// Is there a last line without a line ending?
+    pub none_at_eof: bool,
+    /// The most common line ending, if any; a tie goes to the ending
+    /// that comes first in LF, CRLF, CR, NEL, LS, PS, VT, FF.
+    pub dominant: Option<LineEnding>,
+    /// Is there more than one kind of line ending?
+    pub is_mixed: bool,
+}
+
+impl LineEndingReport {
+
+    /// Create a report from line endings, such as those of line records.
+    ///
+    /// ```
+    /// use read_lines_into::LineEnding;
+    /// use read_lines_into::line_ending_report::LineEndingReport;
+    ///
+    /// let report = LineEndingReport::from_endings([LineEnding::CrLf, LineEnding::None]);
+    /// assert_eq!(report.dominant, Some(LineEnding::CrLf));
+    /// assert!(report.none_at_eof);
+    /// assert!(!report.is_mixed);
+    /// ```
+    pub fn from_endings(endings: impl IntoIterator<Item = LineEnding>) -> Self {
+        let mut report = Self::default();
+        for ending in endings {
+            report.push(ending);
+        }
+        report
+    }
+
+    /// Count one more line ending.
+    pub(crate) fn push(&mut self, ending: LineEnding) {
+        match ending {
+            LineEnding::Lf => self.lf += 1,
+            LineEnding::CrLf => self.crlf += 1,
+            LineEnding::Cr => self.cr += 1,
+            LineEnding::Nel => self.nel += 1,
+            LineEnding::Ls => self.ls += 1,
+            LineEnding::Ps => self.ps += 1,
+            LineEnding::Vt => self.vt += 1,
+            LineEnding::Ff => self.ff += 1,
+            LineEnding::None => self.none_at_eof = true,
+        }
+        let counts = [
+            (self.lf, LineEnding::Lf),
+            (self.crlf, LineEnding::CrLf),
+            (self.cr, LineEnding::Cr),
+            (self.nel, LineEnding::Nel),
+            (self.ls, LineEnding::Ls),
+            (self.ps, LineEnding::Ps),
+            (self.vt, LineEnding::Vt),
+            (self.ff, LineEnding::Ff),
+        ];
+        self.dominant = None;
+        let mut max = 0;
+        for (count, ending) in counts {
+            if count > max {
+                max = count;
+                self.dominant = Some(ending);
+            }
+        }
+        self.is_mixed = counts.iter().filter(|(count, _)| *count > 0).count() > 1;
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty() {
+        assert_eq!(LineEndingReport::from_endings([]), LineEndingReport::default());
+    }
+
+    #[test]
+    fn dominant() {
+        let report = LineEndingReport::from_endings([LineEnding::Cr, LineEnding::CrLf, LineEnding::Cr]);
+        assert_eq!(report.dominant, Some(LineEnding::Cr));
+        assert!(report.is_mixed);
+        assert!(!report.none_at_eof);
+    }
+
+    #[test]
+    fn tie() {
+        let report = LineEndingReport::from_endings([LineEnding::CrLf, LineEnding::Lf]);
+        assert_eq!(report.dominant, Some(LineEnding::Lf));
+    }
+
+    #[test]
+    fn unicode_dominant() {
+        let report = LineEndingReport::from_endings([LineEnding::Ls, LineEnding::Nel, LineEnding::Ls, LineEnding::Lf]);
+        assert_eq!((report.ls, report.nel, report.lf), (2, 1, 1));
+        assert_eq!(report.dominant, Some(LineEnding::Ls));
+        assert!(report.is_mixed);
+    }
+
+    #[test]
+    fn none_at_eof_only() {
+        let report = LineEndingReport::from_endings([LineEnding::None]);
+        assert_eq!(report.dominant, None);
+        assert!(report.none_at_eof);
+        assert!(!report.is_mixed);
+    }
+
+}
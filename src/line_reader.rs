@@ -0,0 +1,438 @@
+//! The one place that reads a line from a `BufRead`, so that every
+//! reader agrees on line numbers, byte offsets, and line endings.
+
+use std::borrow::Cow;
+use std::io::BufRead;
+use std::io::ErrorKind;
+use crate::error::Error;
+use crate::error::Limit;
+use crate::limits::LimitAction;
+use crate::limits::Limits;
+use crate::limits::truncate_point;
+use crate::line::Line;
+use crate::line_ending::LineEnding;
+use crate::line_terminators::LineTerminators;
+use crate::line_terminators::MAX_TERMINATOR_LEN;
+use crate::line_terminators::read_line_bytes_max;
+
+/// A line that borrows its content from the reader's internal buffer.
+#[derive(Debug)]
+pub(crate) struct RawLine<'a> {
+    pub(crate) number: usize,
+    pub(crate) byte_offset: u64,
+    pub(crate) content: Cow<'a, str>,
+    pub(crate) ending: LineEnding,
+}
+
+impl RawLine<'_> {
+
+    /// Copy the line content with its line ending, as in the source.
+    pub(crate) fn with_ending(&self) -> String {
+        let mut string = String::with_capacity(self.content.len() + self.ending.as_str().len());
+        string.push_str(&self.content);
+        string.push_str(self.ending.as_str());
+        string
+    }
+
+    /// Copy the borrowed line into an owned line record.
+    pub(crate) fn to_line(&self) -> Line {
+        Line {
+            number: self.number,
+            byte_offset: self.byte_offset,
+            content: String::from(self.content.as_ref()),
+            ending: self.ending,
+        }
+    }
+
+}
+
+/// Read lines one at a time, reusing one internal buffer.
+#[derive(Debug)]
+pub(crate) struct LineReader<R> {
+    reader: R,
+    terminators: LineTerminators,
+    lossy: bool,
+    limits: Limits,
+    buf: Vec<u8>,
+    number: usize,
+    /// The byte offset of the end of the last line read, from the reader's start.
+    byte_offset: u64,
+    /// The byte offset in the source of the reader's start.
+    origin: u64,
+    /// Set when a limit stops reading.
+    done: bool,
+}
+
+impl<R: BufRead> LineReader<R> {
+
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            terminators: LineTerminators::DEFAULT,
+            lossy: false,
+            limits: Limits::default(),
+            buf: Vec::new(),
+            number: 0,
+            byte_offset: 0,
+            origin: 0,
+            done: false,
+        }
+    }
+
+    pub(crate) fn terminators(mut self, terminators: LineTerminators) -> Self {
+        self.terminators = terminators;
+        self
+    }
+
+    /// Set whether to replace invalid UTF-8 with U+FFFD, rather than error.
+    pub(crate) fn lossy(mut self, lossy: bool) -> Self {
+        self.lossy = lossy;
+        self
+    }
+
+    /// Set resource limits.
+    pub(crate) fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Set the byte offset in the source of the reader's start, such as
+    /// after a seek, so that byte offsets count from the source's start.
+    ///
+    /// The total bytes limit still counts from the reader's start.
+    pub(crate) fn origin(mut self, origin: u64) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Get the byte offset of the end of the last line read.
+    pub(crate) fn byte_offset(&self) -> u64 {
+        self.origin + self.byte_offset
+    }
+
+    pub(crate) fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Read the next line into the internal buffer, and borrow it.
+    ///
+    /// Return `None` at the end of the source.
+    pub(crate) fn read_next(&mut self) -> Option<crate::Result<RawLine<'_>>> {
+        let byte_offset = self.byte_offset();
+        let ending = match self.fill()? {
+            Ok(ending) => ending,
+            Err(e) => return Some(Err(e)),
+        };
+        let content = &self.buf[..self.buf.len() - ending.as_str().len()];
+        let content = match ::std::str::from_utf8(content) {
+            Ok(content) => Cow::Borrowed(content),
+            Err(_) if self.lossy => String::from_utf8_lossy(content),
+            Err(e) => return Some(Err(Error::InvalidUtf8 {
+                path: None,
+                line: Some(self.number),
+                byte_offset: byte_offset + e.valid_up_to() as u64,
+            })),
+        };
+        Some(Ok(RawLine { number: self.number, byte_offset, content, ending }))
+    }
+
+    /// Read the next line into the internal buffer, and borrow
+    /// its content as bytes, without the line ending.
+    ///
+    /// Return `None` at the end of the source.
+    pub(crate) fn read_next_bytes(&mut self) -> Option<crate::Result<(&[u8], LineEnding)>> {
+        let ending = match self.fill()? {
+            Ok(ending) => ending,
+            Err(e) => return Some(Err(e)),
+        };
+        Some(Ok((&self.buf[..self.buf.len() - ending.as_str().len()], ending)))
+    }
+
+    /// Read the next line into the internal buffer, and count it.
+    ///
+    /// Return `None` at the end of the source, or when a limit
+    /// with `LimitAction::Truncate` stops reading.
+    fn fill(&mut self) -> Option<crate::Result<LineEnding>> {
+        self.buf.clear();
+        if self.done {
+            return None;
+        }
+        if let Some((max, action)) = self.limits.max_lines {
+            if self.number >= max {
+                match self.is_at_end() {
+                    Ok(true) => return None,
+                    Ok(false) => return self.exceeded(Limit::Lines(max), action),
+                    Err(e) => return Some(Err(self.read_error(e))),
+                }
+            }
+        }
+        let mut cap = self.limits.max_line_bytes.map_or(usize::MAX, |(max, _)| max.saturating_add(MAX_TERMINATOR_LEN));
+        if let Some((max, _)) = self.limits.max_total_bytes {
+            let room = max.saturating_sub(self.byte_offset).saturating_add(1);
+            cap = cap.min(usize::try_from(room).unwrap_or(usize::MAX));
+        }
+        let mut ending = match read_line_bytes_max(&mut self.reader, self.terminators, &mut self.buf, cap) {
+            Ok(ending) => ending,
+            Err(e) => return Some(Err(self.read_error(e))),
+        };
+        if self.buf.is_empty() {
+            return None;
+        }
+        let mut consumed = self.buf.len() as u64;
+        if let Some(exceeded) = self.check_total_bytes(consumed) {
+            return exceeded;
+        }
+        if let Some((max, action)) = self.limits.max_line_bytes {
+            let content_len = self.buf.len() - ending.map_or(0, |x| x.as_str().len());
+            if ending.is_none() || content_len > max {
+                if action == LimitAction::Error {
+                    return self.exceeded(Limit::LineBytes(max), action);
+                }
+                // Keep the start of the line, at a UTF-8 character boundary,
+                // then skip the rest of the line, keeping the last bytes
+                // in case they are the start of a line terminator.
+                let mut rest = self.buf.split_off(truncate_point(&self.buf, max));
+                while ending.is_none() {
+                    let keep = rest.len().min(MAX_TERMINATOR_LEN - 1);
+                    rest.drain(..rest.len() - keep);
+                    ending = match read_line_bytes_max(&mut self.reader, self.terminators, &mut rest, keep + SKIP_CHUNK_LEN) {
+                        Ok(ending) => ending,
+                        Err(e) => return Some(Err(self.read_error(e))),
+                    };
+                    consumed += (rest.len() - keep) as u64;
+                    if let Some(exceeded) = self.check_total_bytes(consumed) {
+                        return exceeded;
+                    }
+                }
+                self.buf.extend_from_slice(ending.map_or("", |x| x.as_str()).as_bytes());
+            }
+        }
+        self.number += 1;
+        self.byte_offset += consumed;
+        Some(Ok(ending.unwrap_or(LineEnding::None)))
+    }
+
+    /// Check the total bytes limit, given the bytes consumed for the next line.
+    fn check_total_bytes(&mut self, consumed: u64) -> Option<Option<crate::Result<LineEnding>>> {
+        let (max, action) = self.limits.max_total_bytes?;
+        if self.byte_offset + consumed > max {
+            self.buf.clear();
+            return Some(self.exceeded(Limit::TotalBytes(max), action));
+        }
+        None
+    }
+
+    /// Stop reading, because the next line exceeds a limit.
+    fn exceeded(&mut self, limit: Limit, action: LimitAction) -> Option<crate::Result<LineEnding>> {
+        self.done = true;
+        match action {
+            LimitAction::Error => Some(Err(Error::LimitExceeded { path: None, line: Some(self.number + 1), limit })),
+            LimitAction::Truncate => None,
+        }
+    }
+
+    fn read_error(&self, e: ::std::io::Error) -> Error {
+        Error::Read { path: None, line: Some(self.number + 1), source: e }
+    }
+
+    /// Is the source at its end?
+    fn is_at_end(&mut self) -> ::std::io::Result<bool> {
+        loop {
+            match self.reader.fill_buf() {
+                Ok(x) => return Ok(x.is_empty()),
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+}
+
+/// How many bytes to read at a time, when skipping the rest of a truncated line.
+pub(crate) const SKIP_CHUNK_LEN: usize = 8192;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use std::io::Read;
+
+    #[test]
+    fn numbers_and_offsets() {
+        let mut reader = LineReader::new(Cursor::new("lorem\r\nipsum\ndolor"));
+        let line = reader.read_next().unwrap().unwrap().to_line();
+        assert_eq!((line.number, line.byte_offset, line.ending), (1, 0, LineEnding::CrLf));
+        let line = reader.read_next().unwrap().unwrap().to_line();
+        assert_eq!((line.number, line.byte_offset, line.ending), (2, 7, LineEnding::Lf));
+        let line = reader.read_next().unwrap().unwrap().to_line();
+        assert_eq!((line.number, line.byte_offset, line.ending), (3, 13, LineEnding::None));
+        assert!(reader.read_next().is_none());
+    }
+
+    #[test]
+    fn terminators() {
+        let mut reader = LineReader::new(Cursor::new("lorem\ripsum\u{2028}"))
+            .terminators(LineTerminators::ALL);
+        let line = reader.read_next().unwrap().unwrap().to_line();
+        assert_eq!((line.content.as_str(), line.ending), ("lorem", LineEnding::Cr));
+        let line = reader.read_next().unwrap().unwrap().to_line();
+        assert_eq!((line.content.as_str(), line.byte_offset, line.ending), ("ipsum", 6, LineEnding::Ls));
+        assert!(reader.read_next().is_none());
+    }
+
+    #[test]
+    fn lossy() {
+        let mut reader = LineReader::new(Cursor::new(b"caf\xe9\r\nlorem")).lossy(true);
+        let line = reader.read_next().unwrap().unwrap().to_line();
+        assert_eq!((line.content.as_str(), line.ending), ("caf\u{FFFD}", LineEnding::CrLf));
+        let line = reader.read_next().unwrap().unwrap().to_line();
+        assert_eq!((line.content.as_str(), line.byte_offset), ("lorem", 6));
+    }
+
+    #[test]
+    fn bytes() {
+        let mut reader = LineReader::new(Cursor::new(b"caf\xe9\r\nlorem"));
+        assert_eq!(reader.read_next_bytes().unwrap().unwrap(), (&b"caf\xe9"[..], LineEnding::CrLf));
+        assert_eq!(reader.read_next_bytes().unwrap().unwrap(), (&b"lorem"[..], LineEnding::None));
+        assert!(reader.read_next_bytes().is_none());
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let mut reader = LineReader::new(Cursor::new(b"lorem\nipsum\xff\n"));
+        reader.read_next();
+        match reader.read_next().unwrap().unwrap_err() {
+            Error::InvalidUtf8 { line, byte_offset, .. } => assert_eq!((line, byte_offset), (Some(2), 11)),
+            e => panic!("{:?}", e),
+        }
+    }
+
+    /// A line of 10 MB without any line terminator.
+    fn huge_line() -> Vec<u8> {
+        vec![b'a'; 10_000_000]
+    }
+
+    fn limit_of(e: Error) -> (usize, Limit) {
+        match e {
+            Error::LimitExceeded { line, limit, .. } => (line.unwrap(), limit),
+            e => panic!("{:?}", e),
+        }
+    }
+
+    #[test]
+    fn max_line_bytes_error() {
+        let mut reader = LineReader::new(Cursor::new(huge_line()))
+            .limits(Limits::new().max_line_bytes(80, LimitAction::Error));
+        assert_eq!(limit_of(reader.read_next().unwrap().unwrap_err()), (1, Limit::LineBytes(80)));
+        assert!(reader.buf.capacity() < 1024);
+        assert!(reader.read_next().is_none());
+    }
+
+    #[test]
+    fn max_line_bytes_exactly() {
+        let mut reader = LineReader::new(Cursor::new("lorem\r\nipsum"))
+            .limits(Limits::new().max_line_bytes(5, LimitAction::Error));
+        assert_eq!(reader.read_next().unwrap().unwrap().with_ending(), "lorem\r\n");
+        assert_eq!(reader.read_next().unwrap().unwrap().with_ending(), "ipsum");
+        assert!(reader.read_next().is_none());
+    }
+
+    #[test]
+    fn max_line_bytes_truncate() {
+        let bytes = [&b"lorem\n"[..], &huge_line(), b"\r\nipsum"].concat();
+        let mut reader = LineReader::new(Cursor::new(bytes))
+            .limits(Limits::new().max_line_bytes(3, LimitAction::Truncate));
+        let line = reader.read_next().unwrap().unwrap().to_line();
+        assert_eq!((line.content.as_str(), line.ending), ("lor", LineEnding::Lf));
+        let line = reader.read_next().unwrap().unwrap().to_line();
+        assert_eq!((line.content.as_str(), line.byte_offset, line.ending), ("aaa", 6, LineEnding::CrLf));
+        assert!(reader.buf.capacity() < 1024 * 1024);
+        let line = reader.read_next().unwrap().unwrap().to_line();
+        assert_eq!((line.number, line.content.as_str(), line.byte_offset), (3, "ips", 10_000_008));
+        assert!(reader.read_next().is_none());
+    }
+
+    #[test]
+    fn max_line_bytes_truncate_at_char_boundary() {
+        let mut reader = LineReader::new(Cursor::new("😀😀\n"))
+            .limits(Limits::new().max_line_bytes(6, LimitAction::Truncate));
+        assert_eq!(reader.read_next().unwrap().unwrap().with_ending(), "😀\n");
+    }
+
+    #[test]
+    fn max_line_bytes_truncate_with_terminator_across_chunks() {
+        for n in [SKIP_CHUNK_LEN - 2, SKIP_CHUNK_LEN - 1, SKIP_CHUNK_LEN, SKIP_CHUNK_LEN + 1] {
+            for capacity in [1, 2, 3, 8192] {
+                let text = format!("{}\u{2028}{}\r\nipsum", "a".repeat(n), "b".repeat(n));
+                let reader = ::std::io::BufReader::with_capacity(capacity, Cursor::new(text));
+                let mut reader = LineReader::new(reader)
+                    .terminators(LineTerminators::ALL)
+                    .limits(Limits::new().max_line_bytes(1, LimitAction::Truncate));
+                let lines: Vec<String> = ::std::iter::from_fn(|| reader.read_next().map(|x| x.unwrap().with_ending())).collect();
+                assert_eq!(lines, vec!["a\u{2028}", "b\r\n", "i"]);
+            }
+        }
+    }
+
+    #[test]
+    fn max_lines() {
+        let limits = Limits::new().max_lines(2, LimitAction::Error);
+        let mut reader = LineReader::new(Cursor::new("lorem\nipsum\n")).limits(limits);
+        assert!(reader.read_next().unwrap().is_ok());
+        assert!(reader.read_next().unwrap().is_ok());
+        assert!(reader.read_next().is_none());
+        let mut reader = LineReader::new(Cursor::new("lorem\nipsum\ndolor")).limits(limits);
+        reader.read_next();
+        reader.read_next();
+        assert_eq!(limit_of(reader.read_next().unwrap().unwrap_err()), (3, Limit::Lines(2)));
+        assert!(reader.read_next().is_none());
+    }
+
+    #[test]
+    fn max_lines_truncate_endless() {
+        let reader = ::std::io::BufReader::new(::std::io::repeat(b'\n'));
+        let mut reader = LineReader::new(reader).limits(Limits::new().max_lines(1000, LimitAction::Truncate));
+        let mut count = 0;
+        while let Some(line) = reader.read_next() {
+            line.unwrap();
+            count += 1;
+        }
+        assert_eq!(count, 1000);
+    }
+
+    #[test]
+    fn max_total_bytes() {
+        let limits = Limits::new().max_total_bytes(13, LimitAction::Error);
+        let mut reader = LineReader::new(Cursor::new("lorem\r\nipsum\n")).limits(limits);
+        assert!(reader.read_next().unwrap().is_ok());
+        assert!(reader.read_next().unwrap().is_ok());
+        assert!(reader.read_next().is_none());
+        let mut reader = LineReader::new(Cursor::new(huge_line())).limits(limits);
+        assert_eq!(limit_of(reader.read_next().unwrap().unwrap_err()), (1, Limit::TotalBytes(13)));
+        assert!(reader.buf.capacity() < 1024);
+    }
+
+    #[test]
+    fn max_total_bytes_truncate_endless() {
+        let reader = ::std::io::BufReader::new(::std::io::repeat(b'a').take(5).chain(::std::io::repeat(b'\n')));
+        let mut reader = LineReader::new(reader).limits(Limits::new().max_total_bytes(1_000_000, LimitAction::Truncate));
+        let mut count = 0;
+        while let Some(line) = reader.read_next() {
+            line.unwrap();
+            count += 1;
+        }
+        assert_eq!(count, 1_000_000 - 5);
+        assert_eq!(reader.byte_offset, 1_000_000);
+    }
+
+    #[test]
+    fn max_total_bytes_truncate_while_skipping() {
+        let bytes = [&b"lorem\n"[..], &huge_line(), b"\nipsum\n"].concat();
+        let mut reader = LineReader::new(Cursor::new(bytes))
+            .limits(Limits::new().max_line_bytes(3, LimitAction::Truncate).max_total_bytes(100_000, LimitAction::Truncate));
+        assert_eq!(reader.read_next().unwrap().unwrap().with_ending(), "lor\n");
+        assert!(reader.read_next().is_none());
+        assert_eq!(reader.number, 1);
+    }
+
+}
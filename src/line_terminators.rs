@@ -0,0 +1,356 @@
+//! Line terminators, i.e. which line endings split lines.
+//!
+//! The default is `\n` LINE FEED (LF) and `\r\n` CARRIAGE RETURN then
+//! LINE FEED (CRLF), which is the same as Rust `BufRead::lines()`.
+//!
+//! Other line terminators are `\r` CARRIAGE RETURN (CR) as used by
+//! classic Mac OS, plus the Unicode line terminators NEXT LINE (NEL),
+//! LINE SEPARATOR (LS), PARAGRAPH SEPARATOR (PS), LINE TABULATION (VT),
+//! and FORM FEED (FF).
+//!
+//! ```
+//! use std::io::Cursor;
+//! use read_lines_into::options::*;
+//! use read_lines_into::LineTerminators;
+//!
+//! let options = ReadLinesOptions::new()
+//!     .endings(Endings::Clip)
+//!     .terminators(LineTerminators::DEFAULT | LineTerminators::CR);
+//! let strings: Vec<String> = options.read_into(Cursor::new("lorem\ripsum\r\n")).unwrap();
+//! assert_eq!(strings, vec!["lorem", "ipsum"]);
+//! ```
+
+use std::io::BufRead;
+use std::io::ErrorKind;
+use std::ops::BitOr;
+use crate::line_ending::LineEnding;
+
+/// A set of line terminators.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LineTerminators(u8);
+
+impl LineTerminators {
+
+    /// No line terminators, so the whole source is one line.
+    pub const NONE: Self = Self(0);
+
+    /// `\n` LINE FEED (LF).
+    pub const LF: Self = Self(1);
+
+    /// `\r\n` CARRIAGE RETURN then LINE FEED (CRLF).
+    pub const CRLF: Self = Self(1 << 1);
+
+    /// `\r` CARRIAGE RETURN (CR).
+    pub const CR: Self = Self(1 << 2);
+
+    /// `\u{85}` NEXT LINE (NEL).
+    pub const NEL: Self = Self(1 << 3);
+
+    /// `\u{2028}` LINE SEPARATOR (LS).
+    pub const LS: Self = Self(1 << 4);
+
+    /// `\u{2029}` PARAGRAPH SEPARATOR (PS).
+    pub const PS: Self = Self(1 << 5);
+
+    /// `\u{0B}` LINE TABULATION (VT).
+    pub const VT: Self = Self(1 << 6);
+
+    /// `\u{0C}` FORM FEED (FF).
+    pub const FF: Self = Self(1 << 7);
+
+    /// LF and CRLF, which is the same as Rust `BufRead::lines()`.
+    pub const DEFAULT: Self = Self(Self::LF.0 | Self::CRLF.0);
+
+    /// All line terminators, which are the Unicode mandatory line breaks.
+    pub const ALL: Self = Self(u8::MAX);
+
+    /// Does this set contain all the line terminators of the other set?
+    pub fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Is this byte the last byte of any line terminator in this set?
+    pub(crate) fn is_last_byte(self, byte: u8) -> bool {
+        match byte {
+            b'\n' => self.0 & (Self::LF.0 | Self::CRLF.0) != 0,
+            b'\r' => self.contains(Self::CR),
+            0x0B => self.contains(Self::VT),
+            0x0C => self.contains(Self::FF),
+            0x85 => self.contains(Self::NEL),
+            0xA8 => self.contains(Self::LS),
+            0xA9 => self.contains(Self::PS),
+            _ => false,
+        }
+    }
+
+    /// Find the line ending at the end of the bytes, if any,
+    /// knowing that the last byte passed `is_last_byte`.
+    pub(crate) fn classify(self, bytes: &[u8]) -> Classify {
+        match bytes {
+            [.., b'\r', b'\n'] if self.contains(Self::CRLF) => Classify::Ending(LineEnding::CrLf),
+            [.., b'\n'] if self.contains(Self::LF) => Classify::Ending(LineEnding::Lf),
+            [.., b'\r'] if self.contains(Self::CRLF) => Classify::MaybeCrLf,
+            [.., b'\r'] => Classify::Ending(LineEnding::Cr),
+            [.., 0x0B] => Classify::Ending(LineEnding::Vt),
+            [.., 0x0C] => Classify::Ending(LineEnding::Ff),
+            [.., 0xC2, 0x85] => Classify::Ending(LineEnding::Nel),
+            [.., 0xE2, 0x80, 0xA8] => Classify::Ending(LineEnding::Ls),
+            [.., 0xE2, 0x80, 0xA9] => Classify::Ending(LineEnding::Ps),
+            _ => Classify::NotEnding,
+        }
+    }
+
+}
+
+impl Default for LineTerminators {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl BitOr for LineTerminators {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+}
+
+/// What the end of a line's bytes means.
+pub(crate) enum Classify {
+    Ending(LineEnding),
+    /// A `\r` that is a CR line ending, unless a `\n` follows.
+    MaybeCrLf,
+    NotEnding,
+}
+
+/// What to do after scanning one buffer of a `BufRead`.
+enum Step {
+    Ending(LineEnding),
+    MaybeCrLf,
+    More,
+}
+
+/// The most bytes in any line terminator.
+pub(crate) const MAX_TERMINATOR_LEN: usize = 3;
+
+/// Read bytes into a buffer until any line terminator in the set,
+/// including the line terminator, or until the end of the source,
+/// but stop when the buffer has `max` bytes, so memory is bounded.
+///
+/// This is like `BufRead::read_until` for a set of line terminators,
+/// and returns which line ending was found, or `LineEnding::None`
+/// at the end of the source.
+///
+/// Return `None` if the buffer has `max` bytes, and no line terminator
+/// has been found yet. When CR is a line terminator, a CRLF may make
+/// the buffer one byte longer.
+pub(crate) fn read_line_bytes_max<R: BufRead + ?Sized>(
+    reader: &mut R,
+    terminators: LineTerminators,
+    buf: &mut Vec<u8>,
+    max: usize,
+) -> ::std::io::Result<Option<LineEnding>> {
+    let mut maybe_crlf = false;
+    loop {
+        let (used, step) = {
+            let available = match reader.fill_buf() {
+                Ok(x) => x,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            if maybe_crlf {
+                if available.first() == Some(&b'\n') {
+                    buf.push(b'\n');
+                    reader.consume(1);
+                    return Ok(Some(LineEnding::CrLf));
+                }
+                return Ok(Some(LineEnding::Cr));
+            }
+            if available.is_empty() {
+                return Ok(Some(LineEnding::None));
+            }
+            let room = max.saturating_sub(buf.len());
+            if room == 0 {
+                return Ok(None);
+            }
+            scan(terminators, &available[..available.len().min(room)], buf)
+        };
+        reader.consume(used);
+        match step {
+            Step::Ending(ending) => return Ok(Some(ending)),
+            Step::MaybeCrLf => maybe_crlf = true,
+            Step::More => {}
+        }
+    }
+}
+
+/// Scan one buffer for a line terminator, and copy the bytes
+/// through the line terminator, or all the bytes, into `buf`.
+///
+/// Return how many bytes were used, and what to do next.
+fn scan(terminators: LineTerminators, available: &[u8], buf: &mut Vec<u8>) -> (usize, Step) {
+    let mut start = 0;
+    while let Some(j) = available[start..].iter().position(|&b| terminators.is_last_byte(b)) {
+        let i = start + j;
+        buf.extend_from_slice(&available[start..=i]);
+        match terminators.classify(buf) {
+            Classify::Ending(ending) => return (i + 1, Step::Ending(ending)),
+            Classify::MaybeCrLf => match available.get(i + 1) {
+                Some(b'\n') => {
+                    buf.push(b'\n');
+                    return (i + 2, Step::Ending(LineEnding::CrLf));
+                }
+                Some(_) => return (i + 1, Step::Ending(LineEnding::Cr)),
+                None => return (i + 1, Step::MaybeCrLf),
+            },
+            Classify::NotEnding => start = i + 1,
+        }
+    }
+    buf.extend_from_slice(&available[start..]);
+    (available.len(), Step::More)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::BufReader;
+    use crate::options::*;
+
+    /// Read all lines, as (line, ending) pairs, with a given buffer capacity.
+    fn read_all(bytes: &[u8], terminators: LineTerminators, capacity: usize) -> Vec<(Vec<u8>, LineEnding)> {
+        let mut reader = BufReader::with_capacity(capacity, bytes);
+        let mut lines = Vec::new();
+        loop {
+            let mut buf = Vec::new();
+            let ending = super::read_line_bytes_max(&mut reader, terminators, &mut buf, usize::MAX).unwrap().unwrap();
+            if buf.is_empty() { break; }
+            lines.push((buf, ending));
+        }
+        lines
+    }
+
+    fn endings(bytes: &[u8], terminators: LineTerminators) -> Vec<LineEnding> {
+        let lines = read_all(bytes, terminators, 8192);
+        for capacity in 1..8 {
+            assert_eq!(read_all(bytes, terminators, capacity), lines);
+        }
+        lines.into_iter().map(|(_, ending)| ending).collect()
+    }
+
+    #[test]
+    fn read_line_bytes_max() {
+        for capacity in 1..8 {
+            let mut reader = BufReader::with_capacity(capacity, &b"lorem\r\nipsum"[..]);
+            let mut buf = Vec::new();
+            assert_eq!(super::read_line_bytes_max(&mut reader, LineTerminators::DEFAULT, &mut buf, 3).unwrap(), None);
+            assert_eq!(buf, b"lor");
+            assert_eq!(super::read_line_bytes_max(&mut reader, LineTerminators::DEFAULT, &mut buf, 5).unwrap(), None);
+            assert_eq!(buf, b"lorem");
+            assert_eq!(super::read_line_bytes_max(&mut reader, LineTerminators::DEFAULT, &mut buf, 6).unwrap(), None);
+            assert_eq!(buf, b"lorem\r");
+            assert_eq!(super::read_line_bytes_max(&mut reader, LineTerminators::DEFAULT, &mut buf, 7).unwrap(), Some(LineEnding::CrLf));
+            assert_eq!(buf, b"lorem\r\n");
+            buf.clear();
+            assert_eq!(super::read_line_bytes_max(&mut reader, LineTerminators::DEFAULT, &mut buf, 5).unwrap(), Some(LineEnding::None));
+            assert_eq!(buf, b"ipsum");
+        }
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(
+            endings(b"a\nb\r\nc\rd", LineTerminators::DEFAULT),
+            vec![LineEnding::Lf, LineEnding::CrLf, LineEnding::None]
+        );
+    }
+
+    #[test]
+    fn cr() {
+        assert_eq!(
+            endings(b"a\rb\r\nc\r\rd\r", LineTerminators::DEFAULT | LineTerminators::CR),
+            vec![LineEnding::Cr, LineEnding::CrLf, LineEnding::Cr, LineEnding::Cr, LineEnding::Cr]
+        );
+    }
+
+    #[test]
+    fn cr_without_crlf() {
+        assert_eq!(
+            endings(b"a\r\nb", LineTerminators::LF | LineTerminators::CR),
+            vec![LineEnding::Cr, LineEnding::Lf, LineEnding::None]
+        );
+    }
+
+    #[test]
+    fn crlf_without_lf() {
+        assert_eq!(
+            endings(b"a\nb\r\nc", LineTerminators::CRLF),
+            vec![LineEnding::CrLf, LineEnding::None]
+        );
+    }
+
+    #[test]
+    fn unicode() {
+        assert_eq!(
+            endings("a\u{85}b\u{2028}c\u{2029}d\u{0B}e\u{0C}f".as_bytes(), LineTerminators::ALL),
+            vec![LineEnding::Nel, LineEnding::Ls, LineEnding::Ps, LineEnding::Vt, LineEnding::Ff, LineEnding::None]
+        );
+    }
+
+    #[test]
+    fn unicode_lookalikes() {
+        // U+0145 ends with byte 0x85, and U+20A8 ends with bytes 0x82 0xA8.
+        assert_eq!(
+            endings("\u{145}\u{20A8}\u{2028}".as_bytes(), LineTerminators::ALL),
+            vec![LineEnding::Ls]
+        );
+    }
+
+    #[test]
+    fn none() {
+        assert_eq!(
+            endings(b"a\nb\r\n", LineTerminators::NONE),
+            vec![LineEnding::None]
+        );
+    }
+
+    #[test]
+    fn contains() {
+        assert!(LineTerminators::ALL.contains(LineTerminators::DEFAULT));
+        assert!(LineTerminators::DEFAULT.contains(LineTerminators::CRLF));
+        assert!(!LineTerminators::DEFAULT.contains(LineTerminators::CR));
+    }
+
+    #[test]
+    fn fixtures() {
+        let fixtures = [
+            ("example.txt", "\n"),
+            ("example-with-crlf.txt", "\r\n"),
+            ("example-with-cr.txt", "\r"),
+            ("example-with-nel.txt", "\u{85}"),
+            ("example-with-ls.txt", "\u{2028}"),
+            ("example-with-ps.txt", "\u{2029}"),
+            ("example-with-vt.txt", "\u{0B}"),
+            ("example-with-ff.txt", "\u{0C}"),
+        ];
+        for (path, ending) in fixtures {
+            let options = ReadLinesOptions::new().terminators(LineTerminators::ALL);
+            let keep: Vec<String> = options.clone().read_path_into(path).unwrap();
+            assert_eq!(keep, vec![format!("lorem{}", ending), format!("ipsum{}", ending)], "{}", path);
+            let clip: Vec<String> = options.clone().endings(Endings::Clip).read_path_into(path).unwrap();
+            assert_eq!(clip, vec!["lorem", "ipsum"], "{}", path);
+            let trim: String = options.endings(Endings::Clip).whitespace(Whitespace::Trim).read_path_into(path).unwrap();
+            assert_eq!(trim, "loremipsum", "{}", path);
+        }
+    }
+
+    #[test]
+    fn fixture_with_cr_and_default() {
+        let clip: Vec<String> = ReadLinesOptions::new()
+            .endings(Endings::Clip)
+            .read_path_into("example-with-cr.txt")
+            .unwrap();
+        assert_eq!(clip, vec!["lorem\ripsum\r"]);
+    }
+
+}
@@ -0,0 +1,1681 @@
+//! Options for reading lines, as one builder instead of one trait per mode.
+//!
+//! ```
+//! use std::fs::File;
+//! use std::io::BufReader;
+//! use read_lines_into::options::*;
+//!
+//! let options = ReadLinesOptions::new()
+//!     .endings(Endings::Clip)
+//!     .whitespace(Whitespace::Trim)
+//!     .skip_blank(true);
+//!
+//! let file: File = File::open("example.txt").unwrap();
+//! let strings: Vec<String> = options.read_into(BufReader::new(file)).unwrap();
+//! let string: String = options.read_path_into("example.txt").unwrap();
+//! ```
+
+use std::fs::File;
+use std::io::BufRead;
+use std::io::BufReader;
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use std::path::Path;
+use std::str::FromStr;
+use crate::checkpoint::Checkpoint;
+use crate::decoding_reader::DecodingReader;
+use crate::encoding::Encoding;
+use crate::error::Error;
+use crate::limits::LimitAction;
+use crate::limits::Limits;
+use crate::line::Line;
+use crate::line_ending::LineEnding;
+use crate::line_ending::NewlineStyle;
+use crate::line_ending_report::LineEndingReport;
+use crate::line_reader::LineReader;
+use crate::line_terminators::LineTerminators;
+use crate::line_terminators::MAX_TERMINATOR_LEN;
+use crate::parse_lines_error::ParseLinesError;
+use crate::reverse_line_reader::ReverseLineReader;
+
+/// How to handle each line end, such as `\n` or `\r\n`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Endings {
+    /// Keep each line end as is.
+    #[default]
+    Keep,
+    /// Clip each line end.
+    Clip,
+    /// Replace each line end with the given newline style, LF or CRLF.
+    ///
+    /// A last line without a line end stays without a line end.
+    Normalize(NewlineStyle),
+}
+
+/// How to handle whitespace at the start and end of each line.
+///
+/// Whitespace is handled before the line end is put back,
+/// so the line end itself is never trimmed away.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Whitespace {
+    /// Keep whitespace as is.
+    #[default]
+    None,
+    /// Trim whitespace from the start and the end.
+    Trim,
+    /// Trim whitespace from the start.
+    TrimStart,
+    /// Trim whitespace from the end.
+    TrimEnd,
+}
+
+/// Which characters count as whitespace when trimming.
+///
+/// ```
+/// use read_lines_into::options::*;
+///
+/// let options = ReadLinesOptions::new()
+///     .whitespace(Whitespace::Trim)
+///     .trim_chars(TrimChars::Matches(vec!['\t']));
+/// let strings: Vec<String> = options.read_into(" lorem\t\n".as_bytes()).unwrap();
+/// assert_eq!(strings, vec![" lorem\n"]);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub enum TrimChars {
+    /// Unicode whitespace, as in `char::is_whitespace`.
+    #[default]
+    Unicode,
+    /// ASCII whitespace, as in `char::is_ascii_whitespace`,
+    /// which is space, tab, LF, FF, and CR.
+    Ascii,
+    /// Any of the given characters.
+    Matches(Vec<char>),
+    /// Any character for which the given function returns true.
+    Predicate(fn(char) -> bool),
+}
+
+impl TrimChars {
+
+    /// Return true if the character should be trimmed.
+    pub fn matches(&self, c: char) -> bool {
+        match self {
+            TrimChars::Unicode => c.is_whitespace(),
+            TrimChars::Ascii => c.is_ascii_whitespace(),
+            TrimChars::Matches(chars) => chars.contains(&c),
+            TrimChars::Predicate(f) => f(c),
+        }
+    }
+
+    /// Trim the characters from the start and the end of a string.
+    pub fn trim<'a>(&self, s: &'a str) -> &'a str {
+        s.trim_matches(|c| self.matches(c))
+    }
+
+    /// Trim the characters from the start of a string.
+    pub fn trim_start<'a>(&self, s: &'a str) -> &'a str {
+        s.trim_start_matches(|c| self.matches(c))
+    }
+
+    /// Trim the characters from the end of a string.
+    pub fn trim_end<'a>(&self, s: &'a str) -> &'a str {
+        s.trim_end_matches(|c| self.matches(c))
+    }
+
+}
+
+/// Options for reading lines.
+///
+/// The default options keep each line as is, which is the same
+/// as `read_lines_into_string` and `read_lines_into_vec_string`.
+#[derive(Debug, Clone, Default)]
+pub struct ReadLinesOptions {
+    endings: Endings,
+    whitespace: Whitespace,
+    trim_chars: TrimChars,
+    skip_blank: bool,
+    comment_prefixes: Vec<String>,
+    inline_comments: bool,
+    quotes: Option<Vec<char>>,
+    dedent: bool,
+    tab_width: Option<usize>,
+    terminators: LineTerminators,
+    lossy: bool,
+    decode: bool,
+    encoding: Option<Encoding>,
+    strict_endings: bool,
+    limits: Limits,
+    take: Option<usize>,
+    until_blank: bool,
+}
+
+impl ReadLinesOptions {
+
+    /// Create default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set how to handle each line end.
+    pub fn endings(mut self, endings: Endings) -> Self {
+        self.endings = endings;
+        self
+    }
+
+    /// Set how to handle whitespace at the start and end of each line.
+    pub fn whitespace(mut self, whitespace: Whitespace) -> Self {
+        self.whitespace = whitespace;
+        self
+    }
+
+    /// Set which characters count as whitespace when trimming.
+    ///
+    /// The default is `TrimChars::Unicode`, which is the same as `str::trim`.
+    pub fn trim_chars(mut self, trim_chars: TrimChars) -> Self {
+        self.trim_chars = trim_chars;
+        self
+    }
+
+    /// Set whether to skip blank lines, i.e. lines that are empty
+    /// or that contain only whitespace, apart from the line end.
+    pub fn skip_blank(mut self, skip_blank: bool) -> Self {
+        self.skip_blank = skip_blank;
+        self
+    }
+
+    /// Set comment prefixes, such as `#`, `//`, `;`, or `--`, to skip
+    /// comment lines, i.e. lines that start with a comment prefix,
+    /// after any leading whitespace. Empty prefixes are ignored.
+    ///
+    /// Skipped lines still count, so line numbers, such as in errors,
+    /// are the line numbers in the original text.
+    ///
+    /// ```
+    /// use read_lines_into::options::*;
+    ///
+    /// let options = ReadLinesOptions::new().comment_prefixes(&["#", "//"]);
+    /// let strings: Vec<String> = options.read_into("# lorem\nipsum\n  // dolor\n".as_bytes()).unwrap();
+    /// assert_eq!(strings, vec!["ipsum\n"]);
+    /// ```
+    pub fn comment_prefixes(mut self, prefixes: &[&str]) -> Self {
+        self.comment_prefixes = prefixes.iter().filter(|x| !x.is_empty()).map(|x| String::from(*x)).collect();
+        self
+    }
+
+    /// Set whether to also remove inline comments, i.e. from a comment
+    /// prefix to the line end, along with any whitespace before it.
+    ///
+    /// A comment prefix inside a quoted string does not start
+    /// a comment; a backslash escapes a quote inside a quoted
+    /// string. By default, only `"` quotes a string, so that an
+    /// apostrophe, as in "don't", does not hide a comment;
+    /// see `inline_comment_quotes`.
+    ///
+    /// ```
+    /// use read_lines_into::options::*;
+    ///
+    /// let options = ReadLinesOptions::new().comment_prefixes(&["#"]).inline_comments(true);
+    /// let string: String = options.read_into("lorem = \"#ipsum\" # dolor\n".as_bytes()).unwrap();
+    /// assert_eq!(string, "lorem = \"#ipsum\"\n");
+    /// ```
+    pub fn inline_comments(mut self, inline_comments: bool) -> Self {
+        self.inline_comments = inline_comments;
+        self
+    }
+
+    /// Set which characters quote a string, when removing inline comments.
+    ///
+    /// The default is `"`. A string ends at the same character that
+    /// starts it.
+    ///
+    /// ```
+    /// use read_lines_into::options::*;
+    ///
+    /// let options = ReadLinesOptions::new()
+    ///     .comment_prefixes(&["#"])
+    ///     .inline_comments(true)
+    ///     .inline_comment_quotes(&['"', '\'']);
+    /// let string: String = options.read_into("lorem = '#ipsum' # dolor\n".as_bytes()).unwrap();
+    /// assert_eq!(string, "lorem = '#ipsum'\n");
+    /// ```
+    pub fn inline_comment_quotes(mut self, quotes: &[char]) -> Self {
+        self.quotes = Some(quotes.to_vec());
+        self
+    }
+
+    /// Set whether to remove the common leading indentation from all lines,
+    /// like Python's `textwrap.dedent`.
+    ///
+    /// The common indentation is the longest run of spaces and tabs that
+    /// starts every line, so a tab and a space never match each other.
+    /// Lines that contain only spaces and tabs are ignored when finding
+    /// the common indentation, and become empty. Nested indentation is
+    /// kept. This reads every line before returning any, and happens
+    /// last, so skipped lines such as comments don't count, and tabs
+    /// are already expanded.
+    ///
+    /// ```
+    /// use read_lines_into::options::*;
+    ///
+    /// let options = ReadLinesOptions::new().dedent(true);
+    /// let string: String = options.read_into("  lorem\n    ipsum\n".as_bytes()).unwrap();
+    /// assert_eq!(string, "lorem\n  ipsum\n");
+    /// ```
+    pub fn dedent(mut self, dedent: bool) -> Self {
+        self.dedent = dedent;
+        self
+    }
+
+    /// Set a tab width, to expand each tab to spaces up to the next tab stop.
+    ///
+    /// Columns count characters, not bytes, so a multi-byte
+    /// character such as `é` takes one column. A tab width of 0
+    /// removes tabs. This happens before whitespace is trimmed,
+    /// and before dedent.
+    ///
+    /// ```
+    /// use read_lines_into::options::*;
+    ///
+    /// let options = ReadLinesOptions::new().expand_tabs(4);
+    /// let string: String = options.read_into("é\tlorem\n".as_bytes()).unwrap();
+    /// assert_eq!(string, "é   lorem\n");
+    /// ```
+    pub fn expand_tabs(mut self, tab_width: usize) -> Self {
+        self.tab_width = Some(tab_width);
+        self
+    }
+
+    /// Set which line terminators split lines.
+    ///
+    /// The default is `LineTerminators::DEFAULT`, which is LF and CRLF.
+    pub fn terminators(mut self, terminators: LineTerminators) -> Self {
+        self.terminators = terminators;
+        self
+    }
+
+    /// Set whether to replace invalid UTF-8 with U+FFFD REPLACEMENT CHARACTER,
+    /// as in `String::from_utf8_lossy`, rather than return an error.
+    ///
+    /// ```
+    /// use read_lines_into::options::*;
+    ///
+    /// let options = ReadLinesOptions::new().lossy(true);
+    /// let string: String = options.read_into(&b"caf\xe9\n"[..]).unwrap();
+    /// assert_eq!(string, "caf\u{FFFD}\n");
+    /// ```
+    pub fn lossy(mut self, lossy: bool) -> Self {
+        self.lossy = lossy;
+        self
+    }
+
+    /// Set whether to detect the encoding by byte order mark, strip
+    /// the byte order mark, and decode UTF-16 into UTF-8.
+    ///
+    /// Without a byte order mark, the encoding is UTF-8.
+    /// To know which encoding was found, see `DecodingReader`.
+    ///
+    /// Byte offsets, such as in errors and line records, then count
+    /// the decoded UTF-8 bytes after any byte order mark, rather than
+    /// the source bytes, because UTF-16 decodes to different lengths.
+    ///
+    /// ```
+    /// use read_lines_into::options::*;
+    ///
+    /// let options = ReadLinesOptions::new().decode(true);
+    /// let strings: Vec<String> = options.read_path_into("example-with-utf16le.txt").unwrap();
+    /// assert_eq!(strings, vec!["lorem\n", "ipsum\n"]);
+    /// ```
+    pub fn decode(mut self, decode: bool) -> Self {
+        self.decode = decode;
+        self
+    }
+
+    /// Set the encoding, such as for UTF-16 without a byte order mark.
+    ///
+    /// This implies `decode(true)`, and strips a byte order mark
+    /// for the given encoding.
+    pub fn encoding(mut self, encoding: Encoding) -> Self {
+        self.encoding = Some(encoding);
+        self
+    }
+
+    /// Set whether to require every line to end with the dominant
+    /// line ending, as in `LineEndingReport`.
+    ///
+    /// If any line ends differently, then reading returns
+    /// `Error::MixedEndings` for the first such line; a last line
+    /// without a line ending is fine. This reads every line before
+    /// returning any.
+    ///
+    /// ```
+    /// use read_lines_into::options::*;
+    ///
+    /// let options = ReadLinesOptions::new().strict_endings(true);
+    /// let result: read_lines_into::Result<String> = options.read_path_into("example-with-mixed-endings.txt");
+    /// assert_eq!(result.unwrap_err().to_string(), "example-with-mixed-endings.txt:2: line ends with CRLF, but most lines end with LF");
+    /// ```
+    pub fn strict_endings(mut self, strict_endings: bool) -> Self {
+        self.strict_endings = strict_endings;
+        self
+    }
+
+    /// Set resource limits, so that a huge or hostile source can't use
+    /// unbounded memory. The default has no limits.
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_lines_into::Error;
+    /// use read_lines_into::limits::*;
+    /// use read_lines_into::options::*;
+    ///
+    /// let options = ReadLinesOptions::new().limits(Limits::new().max_line_bytes(5, LimitAction::Error));
+    /// match options.read_into::<Vec<String>>(Cursor::new("lorem\nipsum dolor\n")) {
+    ///     Err(Error::LimitExceeded { line, limit, .. }) => assert_eq!((line, limit), (Some(2), Limit::LineBytes(5))),
+    ///     _ => unreachable!(),
+    /// }
+    /// ```
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Set the most lines to read from the source, counting
+    /// skipped lines, such as to read a header.
+    ///
+    /// Reading never reads past the last line it returns, so a
+    /// borrowed reader is positioned right after it, and can be
+    /// used again.
+    ///
+    /// ```
+    /// use std::io::BufRead;
+    /// use std::io::Cursor;
+    /// use read_lines_into::options::*;
+    ///
+    /// let mut cursor = Cursor::new("lorem\nipsum\ndolor\n");
+    /// let head: Vec<String> = ReadLinesOptions::new().take(2).read_into(&mut cursor).unwrap();
+    /// assert_eq!(head, vec!["lorem\n", "ipsum\n"]);
+    /// assert_eq!(cursor.lines().next().unwrap().unwrap(), "dolor");
+    /// ```
+    pub fn take(mut self, n: usize) -> Self {
+        self.take = Some(n);
+        self
+    }
+
+    /// Set whether to stop at the first blank line, i.e. a line that
+    /// is empty apart from its line end, such as after email or HTTP
+    /// headers. The blank line is read, but is not returned.
+    ///
+    /// Reading never reads past the blank line, so a borrowed
+    /// reader is positioned right after it, and can be used again.
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_lines_into::options::*;
+    ///
+    /// let mut cursor = Cursor::new("Host: example.com\r\n\r\nbody\r\n");
+    /// let head: Vec<String> = ReadLinesOptions::new().endings(Endings::Clip).until_blank(true).read_into(&mut cursor).unwrap();
+    /// assert_eq!(head, vec!["Host: example.com"]);
+    /// ```
+    pub fn until_blank(mut self, until_blank: bool) -> Self {
+        self.until_blank = until_blank;
+        self
+    }
+
+    /// Read lines from a `BufRead` into any collection of strings,
+    /// such as `String` or `Vec<String>`.
+    ///
+    /// A `String` gets each line appended, so it is the
+    /// same as concatenating the lines of a `Vec<String>`.
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_lines_into::options::*;
+    ///
+    /// let options = ReadLinesOptions::new().endings(Endings::Clip);
+    /// let strings: Vec<String> = options.read_into(Cursor::new("lorem\r\nipsum\r\n")).unwrap();
+    /// assert_eq!(strings, vec!["lorem", "ipsum"]);
+    /// ```
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_into<C: FromIterator<String>>(&self, reader: impl BufRead) -> crate::Result<C> {
+        self.read_records(reader).map(|x| x.map(|line| self.to_string_with_endings(line))).collect()
+    }
+
+    /// Read lines from a `File` into any collection of strings.
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_file_into<C: FromIterator<String>>(&self, file: &File) -> crate::Result<C> {
+        self.read_into(BufReader::new(file))
+    }
+
+    /// Read lines from a path's file into any collection of strings.
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_path_into<C: FromIterator<String>>(&self, path: impl AsRef<Path>) -> crate::Result<C> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| Error::open(path, e))?;
+        self.read_file_into(&file).map_err(|e| e.with_path(path))
+    }
+
+    /// Read lines from a `BufRead` into any collection of byte vectors,
+    /// without UTF-8 validation.
+    ///
+    /// The options for line endings, terminators, decoding, limits,
+    /// `take`, and `until_blank` apply. Whitespace is trimmed as set by
+    /// `whitespace`, but only ASCII bytes that `trim_chars` matches are
+    /// trimmed. The options that work on text, such as comment prefixes,
+    /// skipping blank lines, dedent, tab expansion, and strict endings,
+    /// don't apply.
+    ///
+    /// ```
+    /// use read_lines_into::options::*;
+    /// use read_lines_into::line_terminators::LineTerminators;
+    ///
+    /// let options = ReadLinesOptions::new()
+    ///     .terminators(LineTerminators::DEFAULT | LineTerminators::CR)
+    ///     .endings(Endings::Clip);
+    /// let lines: Vec<Vec<u8>> = options.read_bytes_into(&b"caf\xe9\rlorem\r\n"[..]).unwrap();
+    /// assert_eq!(lines, vec![b"caf\xe9".to_vec(), b"lorem".to_vec()]);
+    /// ```
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_bytes_into<C: FromIterator<Vec<u8>>>(&self, reader: impl BufRead) -> crate::Result<C> {
+        let mut line_reader = LineReader::new(self.decoding_reader(reader)).terminators(self.terminators).limits(self.line_limits());
+        ::std::iter::from_fn(|| {
+            let (content, ending) = match line_reader.read_next_bytes()? {
+                Ok(line) => line,
+                Err(e) => return Some(Err(e)),
+            };
+            if self.until_blank && content.is_empty() {
+                return None;
+            }
+            let content = self.trim_bytes(content);
+            let ending = self.ending_str(ending);
+            let mut bytes = Vec::with_capacity(content.len() + ending.len());
+            bytes.extend_from_slice(content);
+            bytes.extend_from_slice(ending.as_bytes());
+            Some(Ok(bytes))
+        }).collect()
+    }
+
+    /// Read lines from a `File` into any collection of byte vectors.
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_file_bytes_into<C: FromIterator<Vec<u8>>>(&self, file: &File) -> crate::Result<C> {
+        self.read_bytes_into(BufReader::new(file))
+    }
+
+    /// Read lines from a path's file into any collection of byte vectors.
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_path_bytes_into<C: FromIterator<Vec<u8>>>(&self, path: impl AsRef<Path>) -> crate::Result<C> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| Error::open(path, e))?;
+        self.read_file_bytes_into(&file).map_err(|e| e.with_path(path))
+    }
+
+    /// Read line records from a `BufRead`, each with its line number,
+    /// byte offset, content, and line ending.
+    ///
+    /// The options apply to each record's content; each record's
+    /// line ending is the one in the source, whatever the `endings`
+    /// option. With the default options, the line records are
+    /// lossless, as in `read_lines_into_vec_line`.
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_lines_into::line_ending::LineEnding;
+    /// use read_lines_into::line_terminators::LineTerminators;
+    /// use read_lines_into::options::*;
+    ///
+    /// let options = ReadLinesOptions::new().terminators(LineTerminators::ALL);
+    /// let lines = options.read_lines(Cursor::new("lorem\ripsum\n")).unwrap();
+    /// assert_eq!((lines[0].content.as_str(), lines[0].ending), ("lorem", LineEnding::Cr));
+    /// assert_eq!((lines[1].number, lines[1].byte_offset), (2, 6));
+    /// ```
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_lines(&self, reader: impl BufRead) -> crate::Result<Vec<Line>> {
+        self.read_records(reader).collect()
+    }
+
+    /// Read line records from a `File`.
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_file_lines(&self, file: &File) -> crate::Result<Vec<Line>> {
+        self.read_lines(BufReader::new(file))
+    }
+
+    /// Read line records from a path's file.
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_path_lines(&self, path: impl AsRef<Path>) -> crate::Result<Vec<Line>> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| Error::open(path, e))?;
+        self.read_file_lines(&file).map_err(|e| e.with_path(path))
+    }
+
+    /// Read lines from a `BufRead`, and append them to an existing
+    /// collection of strings, such as `Vec<String>` or `HashSet<String>`.
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_lines_into::options::*;
+    ///
+    /// let mut strings = vec![String::from("header")];
+    /// ReadLinesOptions::new().endings(Endings::Clip).read_extend(Cursor::new("lorem\n"), &mut strings).unwrap();
+    /// assert_eq!(strings, vec!["header", "lorem"]);
+    /// ```
+    ///
+    /// Any error will return immediately, and the lines
+    /// before the error will already be in the collection.
+    ///
+    pub fn read_extend(&self, reader: impl BufRead, collection: &mut impl Extend<String>) -> crate::Result<()> {
+        let mut error = None;
+        collection.extend(self.read_records(reader).map_while(|x| match x {
+            Ok(line) => Some(self.to_string_with_endings(line)),
+            Err(e) => {
+                error = Some(e);
+                None
+            }
+        }));
+        error.map_or(Ok(()), Err)
+    }
+
+    /// Read lines from a `BufRead` into a `String`, with a separator
+    /// between lines, but not after the last line.
+    ///
+    /// You may want to clip each line, so that lines are kept apart
+    /// only by the separator.
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_lines_into::options::*;
+    ///
+    /// let options = ReadLinesOptions::new().endings(Endings::Clip).whitespace(Whitespace::Trim);
+    /// let string = options.read_joined(Cursor::new("  lorem\r\n  ipsum\r\n"), ", ").unwrap();
+    /// assert_eq!(string, "lorem, ipsum");
+    /// ```
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_joined(&self, reader: impl BufRead, separator: &str) -> crate::Result<String> {
+        let mut string = String::new();
+        for (i, line) in self.read_records(reader).enumerate() {
+            if i > 0 {
+                string.push_str(separator);
+            }
+            string.push_str(&self.to_string_with_endings(line?));
+        }
+        Ok(string)
+    }
+
+    /// Read lines from a `File` into a `String`, with a separator
+    /// between lines.
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_file_joined(&self, file: &File, separator: &str) -> crate::Result<String> {
+        self.read_joined(BufReader::new(file), separator)
+    }
+
+    /// Read lines from a path's file into a `String`, with a separator
+    /// between lines.
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_path_joined(&self, path: impl AsRef<Path>, separator: &str) -> crate::Result<String> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| Error::open(path, e))?;
+        self.read_file_joined(&file, separator).map_err(|e| e.with_path(path))
+    }
+
+    /// Parse each line into a typed value, using `FromStr`.
+    ///
+    /// The options apply before parsing, so you may want to
+    /// clip and trim each line, and skip blank and comment lines.
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_lines_into::options::*;
+    ///
+    /// let options = ReadLinesOptions::new()
+    ///     .endings(Endings::Clip)
+    ///     .whitespace(Whitespace::Trim)
+    ///     .skip_blank(true)
+    ///     .comment_prefixes(&["#"]);
+    /// let numbers: Vec<u64> = options.read_parsed(Cursor::new("# lorem\n1\n\n2\n")).unwrap();
+    /// assert_eq!(numbers, vec![1, 2]);
+    /// ```
+    ///
+    /// Any error will return immediately. A parse error
+    /// has the line number in the original text.
+    ///
+    pub fn read_parsed<T: FromStr>(&self, reader: impl BufRead) -> Result<Vec<T>, ParseLinesError<T::Err>> {
+        self.read_records(reader).map(|x| {
+            let line = x?;
+            let number = line.number;
+            let content = self.to_string_with_endings(line);
+            match content.parse::<T>() {
+                Ok(value) => Ok(value),
+                Err(source) => Err(ParseLinesError::Parse { path: None, line: number, content, source }),
+            }
+        }).collect()
+    }
+
+    /// Parse each line of a `File` into a typed value, using `FromStr`.
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_file_parsed<T: FromStr>(&self, file: &File) -> Result<Vec<T>, ParseLinesError<T::Err>> {
+        self.read_parsed(BufReader::new(file))
+    }
+
+    /// Parse each line of a path's file into a typed value, using `FromStr`.
+    ///
+    /// Any error will return immediately. A parse error has the path.
+    ///
+    pub fn read_path_parsed<T: FromStr>(&self, path: impl AsRef<Path>) -> Result<Vec<T>, ParseLinesError<T::Err>> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| Error::open(path, e))?;
+        self.read_file_parsed(&file).map_err(|e| e.with_path(path))
+    }
+
+    /// Read lines from a `BufRead` into a `LineEndingReport`;
+    /// count each line ending that the terminators split on.
+    ///
+    /// This reads bytes, so the source does not need to be valid UTF-8.
+    /// The terminators, decoding, limits, `take`, and `until_blank`
+    /// apply; the options that change line content do not.
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use read_lines_into::limits::*;
+    /// use read_lines_into::options::*;
+    ///
+    /// let options = ReadLinesOptions::new().limits(Limits::new().max_line_bytes(5, LimitAction::Error));
+    /// let report = options.read_line_ending_report(Cursor::new("lorem\r\nipsum\n")).unwrap();
+    /// assert_eq!((report.lf, report.crlf), (1, 1));
+    /// assert!(options.read_line_ending_report(Cursor::new("lorem ipsum\n")).is_err());
+    /// ```
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_line_ending_report(&self, reader: impl BufRead) -> crate::Result<LineEndingReport> {
+        let mut line_reader = LineReader::new(self.decoding_reader(reader))
+            .terminators(self.terminators)
+            .limits(self.line_limits());
+        let mut report = LineEndingReport::default();
+        while let Some(x) = line_reader.read_next_bytes() {
+            let (content, ending) = x?;
+            if self.until_blank && content.is_empty() {
+                break;
+            }
+            report.push(ending);
+        }
+        Ok(report)
+    }
+
+    /// Read lines from a `File` into a `LineEndingReport`.
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_file_line_ending_report(&self, file: &File) -> crate::Result<LineEndingReport> {
+        self.read_line_ending_report(BufReader::new(file))
+    }
+
+    /// Read lines from a path's file into a `LineEndingReport`.
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_path_line_ending_report(&self, path: impl AsRef<Path>) -> crate::Result<LineEndingReport> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| Error::open(path, e))?;
+        self.read_file_line_ending_report(&file).map_err(|e| e.with_path(path))
+    }
+
+    /// Read lines from a `File` into Vec<String> from a checkpoint,
+    /// and return a checkpoint at the end of the last line read.
+    ///
+    /// A last line without a line ending is not read, because more of
+    /// the line may be appended; the next read gets the whole line.
+    /// Limits apply to each read from a checkpoint, so a last line that
+    /// is longer than `max_line_bytes` is an error, or is skipped to
+    /// the end of the file, rather than buffered whole. Decoding
+    /// doesn't apply, because a checkpoint is a byte offset in the file.
+    ///
+    /// ```
+    /// use std::fs::File;
+    /// use read_lines_into::Checkpoint;
+    /// use read_lines_into::options::*;
+    ///
+    /// let options = ReadLinesOptions::new().endings(Endings::Clip);
+    /// let file = File::open("example.txt").unwrap();
+    /// let (strings, checkpoint) = options.read_file_from_offset(&file, Checkpoint::new()).unwrap();
+    /// assert_eq!(strings, vec!["lorem", "ipsum"]);
+    /// assert_eq!(checkpoint.offset, 12);
+    /// ```
+    ///
+    /// Any error will return immediately, including when the
+    /// checkpoint is for a different file, or the file is truncated.
+    /// An error's byte offset is from the start of the file, but its
+    /// line number is from the checkpoint.
+    ///
+    pub fn read_file_from_offset(&self, file: &File, checkpoint: Checkpoint) -> crate::Result<(Vec<String>, Checkpoint)> {
+        checkpoint.check(&file.metadata()?)?;
+        let mut reader = file;
+        reader.seek(SeekFrom::Start(checkpoint.offset))?;
+        let mut line_reader = LineReader::new(BufReader::new(reader))
+            .terminators(self.terminators)
+            .lossy(self.lossy)
+            .limits(self.line_limits())
+            .origin(checkpoint.offset);
+        let mut lines = Vec::new();
+        let mut offset = checkpoint.offset;
+        while let Some(line) = line_reader.read_next() {
+            let line = line?;
+            if line.ending == LineEnding::None {
+                break;
+            }
+            let stop = self.until_blank && line.content.is_empty();
+            if !stop {
+                lines.push(line.to_line());
+            }
+            offset = line_reader.byte_offset();
+            if stop {
+                break;
+            }
+        }
+        let strings = self.finish(lines)?.into_iter().map(|line| self.to_string_with_endings(line)).collect();
+        Ok((strings, Checkpoint::of(&file.metadata()?, offset)))
+    }
+
+    /// Read lines from a path's file into Vec<String> from a checkpoint,
+    /// and return a checkpoint at the end of the last line read.
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_path_from_offset(&self, path: impl AsRef<Path>, checkpoint: Checkpoint) -> crate::Result<(Vec<String>, Checkpoint)> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| Error::open(path, e))?;
+        self.read_file_from_offset(&file, checkpoint).map_err(|e| e.with_path(path))
+    }
+
+    /// Read the last n lines from a seekable source into any collection
+    /// of strings.
+    ///
+    /// This seeks backwards from the end, so it reads about as many
+    /// bytes as the last `n` lines, rather than the whole source. Lines
+    /// split the same way as reading forwards. The options apply to each
+    /// of the last `n` lines, so skipped lines make fewer lines; limits
+    /// count from the end. Decoding, dedent, strict endings, `take`,
+    /// and `until_blank` don't apply.
+    ///
+    /// ```
+    /// use read_lines_into::options::*;
+    /// use read_lines_into::line_terminators::LineTerminators;
+    ///
+    /// let options = ReadLinesOptions::new()
+    ///     .terminators(LineTerminators::DEFAULT | LineTerminators::CR)
+    ///     .endings(Endings::Clip);
+    /// let strings: Vec<String> = options.read_path_last_into("example-with-cr.txt", 1).unwrap();
+    /// assert_eq!(strings, vec!["ipsum"]);
+    /// ```
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_last_into<C: FromIterator<String>>(&self, reader: impl Read + Seek, n: usize) -> crate::Result<C> {
+        let lines = ReverseLineReader::new(reader)
+            .terminators(self.terminators)
+            .lossy(self.lossy)
+            .limits(self.limits)
+            .read_last(n)?;
+        Ok(lines
+            .into_iter()
+            .filter_map(|(content, ending)| self.apply(&content).map(|string| string + self.ending_str(ending)))
+            .collect())
+    }
+
+    /// Read the last n lines from a `File` into any collection of strings.
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_file_last_into<C: FromIterator<String>>(&self, file: &File, n: usize) -> crate::Result<C> {
+        self.read_last_into(BufReader::new(file), n)
+    }
+
+    /// Read the last n lines from a path's file into any collection of strings.
+    ///
+    /// Any error will return immediately.
+    ///
+    pub fn read_path_last_into<C: FromIterator<String>>(&self, path: impl AsRef<Path>, n: usize) -> crate::Result<C> {
+        let path = path.as_ref();
+        let file = File::open(path).map_err(|e| Error::open(path, e))?;
+        self.read_file_last_into(&file, n).map_err(|e| e.with_path(path))
+    }
+
+    /// Read line records, with their line numbers and byte offsets
+    /// in the original text, skipping the lines that the options skip.
+    ///
+    /// Each record's content has the options applied, apart from
+    /// line endings; each record's ending is the one in the source.
+    fn read_records<'a>(&'a self, reader: impl BufRead + 'a) -> impl Iterator<Item = crate::Result<Line>> + 'a {
+        let mut line_reader = LineReader::new(self.decoding_reader(reader)).terminators(self.terminators).lossy(self.lossy).limits(self.line_limits());
+        let mut buffered: Option<::std::vec::IntoIter<Line>> = None;
+        let mut stopped = false;
+        ::std::iter::from_fn(move || {
+            loop {
+                if self.strict_endings || self.dedent {
+                    if buffered.is_none() {
+                        match self.read_buffered(&mut line_reader) {
+                            Ok(lines) => buffered = Some(lines.into_iter()),
+                            Err(e) => {
+                                buffered = Some(Vec::new().into_iter());
+                                return Some(Err(e));
+                            }
+                        }
+                    }
+                    return buffered.as_mut()?.next().map(Ok);
+                } else {
+                    if stopped {
+                        return None;
+                    }
+                    match line_reader.read_next()? {
+                        Ok(line) => {
+                            if self.until_blank && line.content.is_empty() {
+                                stopped = true;
+                                return None;
+                            }
+                            if let Some(content) = self.apply(&line.content) {
+                                return Some(Ok(Line { number: line.number, byte_offset: line.byte_offset, content, ending: line.ending }));
+                            }
+                        }
+                        Err(e) => return Some(Err(e)),
+                    }
+                }
+            }
+        })
+    }
+
+    /// Wrap a reader, so that it decodes the source as the options say.
+    fn decoding_reader<R: BufRead>(&self, reader: R) -> DecodingReader<R> {
+        match self.encoding {
+            Some(encoding) => DecodingReader::with_encoding(reader, encoding),
+            None if self.decode => DecodingReader::new(reader),
+            None => DecodingReader::pass_through(reader),
+        }
+    }
+
+    /// Get the limits, with the most lines to read set by `take`.
+    fn line_limits(&self) -> Limits {
+        let mut limits = self.limits;
+        if let Some(n) = self.take {
+            if limits.max_lines.is_none_or(|(max, _)| n < max) {
+                limits.max_lines = Some((n, LimitAction::Truncate));
+            }
+        }
+        limits
+    }
+
+    /// Read every line, then finish the lines.
+    fn read_buffered<R: BufRead>(&self, line_reader: &mut LineReader<R>) -> crate::Result<Vec<Line>> {
+        let mut lines = Vec::<Line>::new();
+        while let Some(line) = line_reader.read_next() {
+            let line = line?;
+            if self.until_blank && line.content.is_empty() {
+                break;
+            }
+            lines.push(line.to_line());
+        }
+        self.finish(lines)
+    }
+
+    /// Check endings, apply the options, and dedent the lines
+    /// that are kept, as needed.
+    fn finish(&self, lines: Vec<Line>) -> crate::Result<Vec<Line>> {
+        if self.strict_endings {
+            check_endings(&lines)?;
+        }
+        let mut lines: Vec<Line> = lines
+            .into_iter()
+            .filter_map(|line| self.apply(&line.content).map(|content| Line { content, ..line }))
+            .collect();
+        if self.dedent {
+            dedent(&mut lines);
+        }
+        Ok(lines)
+    }
+
+    /// Apply the options to one line's content, apart from line endings.
+    ///
+    /// Return `None` if the line is skipped.
+    fn apply(&self, content: &str) -> Option<String> {
+        let expanded;
+        let content = match self.tab_width {
+            Some(tab_width) if content.contains('\t') => {
+                expanded = expand_tabs(content, tab_width);
+                expanded.as_str()
+            }
+            _ => content,
+        };
+        if self.comment_prefixes.iter().any(|x| content.trim_start().starts_with(x.as_str())) {
+            return None;
+        }
+        let content = if self.inline_comments {
+            strip_inline_comment(content, &self.comment_prefixes, self.quotes.as_deref().unwrap_or(&['"']))
+        } else {
+            content
+        };
+        let content = match self.whitespace {
+            Whitespace::None => content,
+            Whitespace::Trim => self.trim_chars.trim(content),
+            Whitespace::TrimStart => self.trim_chars.trim_start(content),
+            Whitespace::TrimEnd => self.trim_chars.trim_end(content),
+        };
+        if self.skip_blank && content.trim().is_empty() {
+            return None;
+        }
+        let mut string = String::with_capacity(content.len() + MAX_TERMINATOR_LEN);
+        string.push_str(content);
+        Some(string)
+    }
+
+    /// Convert a line record to a string, handling its line ending.
+    fn to_string_with_endings(&self, line: Line) -> String {
+        let mut string = line.content;
+        string.push_str(self.ending_str(line.ending));
+        string
+    }
+
+    /// Get the line ending to put back at the end of a line.
+    fn ending_str(&self, ending: LineEnding) -> &'static str {
+        match self.endings {
+            Endings::Keep => ending.as_str(),
+            Endings::Clip => "",
+            Endings::Normalize(x) => if ending == LineEnding::None { "" } else { x.as_str() },
+        }
+    }
+
+    /// Trim a line's bytes of the ASCII characters that `trim_chars` matches.
+    fn trim_bytes<'a>(&self, bytes: &'a [u8]) -> &'a [u8] {
+        let matches = |b: &u8| b.is_ascii() && self.trim_chars.matches(char::from(*b));
+        let start = match self.whitespace {
+            Whitespace::Trim | Whitespace::TrimStart => bytes.iter().position(|b| !matches(b)).unwrap_or(bytes.len()),
+            _ => 0,
+        };
+        let bytes = &bytes[start..];
+        let end = match self.whitespace {
+            Whitespace::Trim | Whitespace::TrimEnd => bytes.iter().rposition(|b| !matches(b)).map_or(0, |i| i + 1),
+            _ => bytes.len(),
+        };
+        &bytes[..end]
+    }
+
+}
+
+/// Check that every line ends with the dominant line ending,
+/// apart from a last line without a line ending.
+fn check_endings(lines: &[Line]) -> crate::Result<()> {
+    let report = LineEndingReport::from_endings(lines.iter().map(|line| line.ending));
+    if let Some(dominant) = report.dominant {
+        let line = lines.iter().find(|line| line.ending != dominant && line.ending != LineEnding::None);
+        if let Some(line) = line {
+            return Err(Error::MixedEndings {
+                path: None,
+                line: line.number,
+                found: line.ending,
+                expected: dominant,
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Remove the common leading indentation from all lines,
+/// and empty the lines that contain only spaces and tabs.
+fn dedent(lines: &mut [Line]) {
+    let is_indent = |c: char| c == ' ' || c == '\t';
+    let mut prefix: Option<&str> = None;
+    for line in lines.iter() {
+        let content = line.content.as_str();
+        let indent = &content[..content.len() - content.trim_start_matches(is_indent).len()];
+        if indent.len() == content.len() {
+            continue;
+        }
+        prefix = Some(match prefix {
+            None => indent,
+            Some(prefix) => {
+                let len = prefix.bytes().zip(indent.bytes()).take_while(|(a, b)| a == b).count();
+                &prefix[..len]
+            }
+        });
+    }
+    let len = prefix.map_or(0, str::len);
+    for line in lines.iter_mut() {
+        if line.content.chars().all(is_indent) {
+            line.content.clear();
+        } else {
+            line.content.drain(..len);
+        }
+    }
+}
+
+/// Remove an inline comment, and the whitespace before it,
+/// unless the comment prefix is inside a string quoted by any of the quotes.
+fn strip_inline_comment<'a>(content: &'a str, prefixes: &[String], quotes: &[char]) -> &'a str {
+    let mut quote: Option<char> = None;
+    let mut escaped = false;
+    for (i, c) in content.char_indices() {
+        match quote {
+            Some(q) => {
+                if escaped {
+                    escaped = false;
+                } else if c == '\\' {
+                    escaped = true;
+                } else if c == q {
+                    quote = None;
+                }
+            }
+            None => {
+                if prefixes.iter().any(|x| content[i..].starts_with(x.as_str())) {
+                    return content[..i].trim_end();
+                }
+                if quotes.contains(&c) {
+                    quote = Some(c);
+                }
+            }
+        }
+    }
+    content
+}
+
+/// Expand each tab to spaces up to the next tab stop,
+/// counting columns by characters.
+fn expand_tabs(content: &str, tab_width: usize) -> String {
+    let mut string = String::with_capacity(content.len());
+    let mut column = 0;
+    for c in content.chars() {
+        if c == '\t' {
+            if tab_width > 0 {
+                let spaces = tab_width - column % tab_width;
+                string.extend(::std::iter::repeat_n(' ', spaces));
+                column += spaces;
+            }
+        } else {
+            string.push(c);
+            column += 1;
+        }
+    }
+    string
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use std::io::ErrorKind;
+    use crate::error::Limit;
+    use crate::limits::LimitAction;
+
+    const ENDINGS: [Endings; 4] = [
+        Endings::Keep,
+        Endings::Clip,
+        Endings::Normalize(NewlineStyle::Lf),
+        Endings::Normalize(NewlineStyle::CrLf),
+    ];
+
+    const WHITESPACES: [Whitespace; 4] = [
+        Whitespace::None,
+        Whitespace::Trim,
+        Whitespace::TrimStart,
+        Whitespace::TrimEnd,
+    ];
+
+    const PATHS: [&str; 3] = [
+        "example.txt",
+        "example-with-crlf.txt",
+        "example-with-indent.txt",
+    ];
+
+    /// Expected lines, computed independently of `apply`.
+    fn expect(text: &str, endings: Endings, whitespace: Whitespace, skip_blank: bool) -> Vec<String> {
+        text.split_inclusive('\n').filter_map(|line| {
+            let ending = if line.ends_with("\r\n") { "\r\n" } else if line.ends_with('\n') { "\n" } else { "" };
+            let content = &line[..line.len() - ending.len()];
+            let content = match whitespace {
+                Whitespace::None => content,
+                Whitespace::Trim => content.trim(),
+                Whitespace::TrimStart => content.trim_start(),
+                Whitespace::TrimEnd => content.trim_end(),
+            };
+            if skip_blank && content.trim().is_empty() {
+                return None;
+            }
+            let ending = match (endings, ending) {
+                (_, "") | (Endings::Clip, _) => "",
+                (Endings::Keep, x) => x,
+                (Endings::Normalize(x), _) => x.as_str(),
+            };
+            Some(format!("{}{}", content, ending))
+        }).collect()
+    }
+
+    #[test]
+    fn every_combination_with_every_fixture() {
+        for path in PATHS {
+            let text = ::std::fs::read_to_string(path).unwrap();
+            for endings in ENDINGS {
+                for whitespace in WHITESPACES {
+                    for skip_blank in [false, true] {
+                        let options = ReadLinesOptions::new()
+                            .endings(endings)
+                            .whitespace(whitespace)
+                            .skip_blank(skip_blank);
+                        let expect = expect(&text, endings, whitespace, skip_blank);
+                        let strings: Vec<String> = options.read_path_into(path).unwrap();
+                        assert_eq!(strings, expect, "{:?} {:?}", path, options);
+                        let string: String = options.read_path_into(path).unwrap();
+                        assert_eq!(string, expect.concat(), "{:?} {:?}", path, options);
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn default_keeps_lines() {
+        let strings: Vec<String> = ReadLinesOptions::new()
+            .read_into(Cursor::new("lorem\nipsum\r\ndolor"))
+            .unwrap();
+        assert_eq!(strings, vec!["lorem\n", "ipsum\r\n", "dolor"]);
+    }
+
+    #[test]
+    fn trim_keeps_line_end() {
+        let strings: Vec<String> = ReadLinesOptions::new()
+            .whitespace(Whitespace::Trim)
+            .read_into(Cursor::new("  lorem  \r\n\tipsum\t\n"))
+            .unwrap();
+        assert_eq!(strings, vec!["lorem\r\n", "ipsum\n"]);
+    }
+
+    #[test]
+    fn normalize_keeps_missing_last_line_end() {
+        let string: String = ReadLinesOptions::new()
+            .endings(Endings::Normalize(NewlineStyle::CrLf))
+            .read_into(Cursor::new("lorem\nipsum\r\ndolor"))
+            .unwrap();
+        assert_eq!(string, "lorem\r\nipsum\r\ndolor");
+    }
+
+    #[test]
+    fn trim_chars() {
+        let text = " \u{3000}lorem\t \u{0B}\r\n\t\tipsum\t\n";
+        let read = |whitespace, trim_chars| -> Vec<String> {
+            ReadLinesOptions::new()
+                .whitespace(whitespace)
+                .trim_chars(trim_chars)
+                .read_into(Cursor::new(text))
+                .unwrap()
+        };
+        assert_eq!(read(Whitespace::Trim, TrimChars::Unicode), vec!["lorem\r\n", "ipsum\n"]);
+        assert_eq!(read(Whitespace::Trim, TrimChars::Ascii), vec!["\u{3000}lorem\t \u{0B}\r\n", "ipsum\n"]);
+        assert_eq!(read(Whitespace::TrimStart, TrimChars::Matches(vec!['\t'])), vec![" \u{3000}lorem\t \u{0B}\r\n", "ipsum\t\n"]);
+        assert_eq!(read(Whitespace::TrimEnd, TrimChars::Matches(vec!['\t'])), vec![" \u{3000}lorem\t \u{0B}\r\n", "\t\tipsum\n"]);
+        assert_eq!(read(Whitespace::Trim, TrimChars::Predicate(|c| c == ' ' || c == '\u{0B}')), vec!["\u{3000}lorem\t\r\n", "\t\tipsum\t\n"]);
+    }
+
+    #[test]
+    fn dedent() {
+        let read = |text: &str| -> String {
+            ReadLinesOptions::new().dedent(true).read_into(Cursor::new(text)).unwrap()
+        };
+        assert_eq!(read("    lorem\n      ipsum\n    dolor"), "lorem\n  ipsum\ndolor");
+        assert_eq!(read("lorem\n    ipsum\n"), "lorem\n    ipsum\n");
+        assert_eq!(read("  lorem\r\n\r\n \t \r\n    ipsum\r\n"), "lorem\r\n\r\n\r\n  ipsum\r\n");
+        assert_eq!(read("\t  lorem\n\t\tipsum\n"), "  lorem\n\tipsum\n");
+        assert_eq!(read("  lorem\n\tipsum\n"), "  lorem\n\tipsum\n");
+        assert_eq!(read(" \n\t\n"), "\n\n");
+        assert_eq!(read(""), "");
+    }
+
+    #[test]
+    fn dedent_then_trim_end() {
+        let strings: Vec<String> = ReadLinesOptions::new()
+            .dedent(true)
+            .whitespace(Whitespace::TrimEnd)
+            .endings(Endings::Clip)
+            .read_into(Cursor::new("  lorem  \n    ipsum  \n"))
+            .unwrap();
+        assert_eq!(strings, vec!["lorem", "  ipsum"]);
+    }
+
+    #[test]
+    fn dedent_skips_comments() {
+        let options = ReadLinesOptions::new().dedent(true).comment_prefixes(&["#"]);
+        let string: String = options.read_into(Cursor::new("# c\n    a\n      b\n")).unwrap();
+        assert_eq!(string, "a\n  b\n");
+        let string: String = options.skip_blank(true).read_into(Cursor::new("  a\n\n    b\n")).unwrap();
+        assert_eq!(string, "a\n  b\n");
+    }
+
+    #[test]
+    fn expand_tabs() {
+        let read = |text: &str, tab_width| -> String {
+            ReadLinesOptions::new().expand_tabs(tab_width).read_into(Cursor::new(text)).unwrap()
+        };
+        assert_eq!(read("\tlorem\tipsum\r\n", 4), "    lorem   ipsum\r\n");
+        assert_eq!(read("ab\tc\n\t\td", 8), "ab      c\n                d");
+        assert_eq!(read("日本\tlorem\n", 4), "日本  lorem\n");
+        assert_eq!(read("lorem\t\tipsum\n", 0), "loremipsum\n");
+        assert_eq!(read("lorem\n", 4), "lorem\n");
+    }
+
+    #[test]
+    fn expand_tabs_with_dedent_and_trim() {
+        let strings: Vec<String> = ReadLinesOptions::new()
+            .dedent(true)
+            .expand_tabs(4)
+            .whitespace(Whitespace::TrimEnd)
+            .endings(Endings::Clip)
+            .read_into(Cursor::new("\tlorem\t\n\t\tipsum\tdolor\t\n"))
+            .unwrap();
+        assert_eq!(strings, vec!["lorem", "    ipsum   dolor"]);
+    }
+
+    #[test]
+    fn comment_prefixes() {
+        let strings: Vec<String> = ReadLinesOptions::new()
+            .comment_prefixes(&["#", "//", ";", "--", ""])
+            .whitespace(Whitespace::Trim)
+            .skip_blank(true)
+            .endings(Endings::Clip)
+            .read_into(Cursor::new("# lorem\nipsum\n\n  // dolor\n;sit\n-- amet\nconsectetur # adipiscing\n"))
+            .unwrap();
+        assert_eq!(strings, vec!["ipsum", "consectetur # adipiscing"]);
+    }
+
+    #[test]
+    fn inline_comments() {
+        let read = |text: &str| -> Vec<String> {
+            ReadLinesOptions::new()
+                .comment_prefixes(&["#", "//"])
+                .inline_comments(true)
+                .endings(Endings::Clip)
+                .read_into(Cursor::new(text))
+                .unwrap()
+        };
+        assert_eq!(read("lorem # ipsum"), vec!["lorem"]);
+        assert_eq!(read("url = \"http://example.com\" // dolor"), vec!["url = \"http://example.com\""]);
+        assert_eq!(read("lorem = '#ipsum' #dolor"), vec!["lorem = '"]);
+        assert_eq!(read("don't do it # comment"), vec!["don't do it"]);
+        assert_eq!(read("lorem = \"\\\"#ipsum\" #dolor"), vec!["lorem = \"\\\"#ipsum\""]);
+        assert_eq!(read("lorem = \"#ipsum"), vec!["lorem = \"#ipsum"]);
+        assert_eq!(read("  lorem\t"), vec!["  lorem\t"]);
+    }
+
+    #[test]
+    fn inline_comment_quotes() {
+        let read = |text: &str, quotes: &[char]| -> Vec<String> {
+            ReadLinesOptions::new()
+                .comment_prefixes(&["#"])
+                .inline_comments(true)
+                .inline_comment_quotes(quotes)
+                .endings(Endings::Clip)
+                .read_into(Cursor::new(text))
+                .unwrap()
+        };
+        assert_eq!(read("lorem = '#ipsum' #dolor", &['"', '\'']), vec!["lorem = '#ipsum'"]);
+        assert_eq!(read("lorem = \"#ipsum\" #dolor", &['\'']), vec!["lorem = \""]);
+        assert_eq!(read("lorem = `#ipsum` #dolor", &['`']), vec!["lorem = `#ipsum`"]);
+        assert_eq!(read("lorem = \"#ipsum\" #dolor", &[]), vec!["lorem = \""]);
+    }
+
+    #[test]
+    fn comment_lines_keep_line_numbers() {
+        let e = ReadLinesOptions::new()
+            .comment_prefixes(&["#"])
+            .strict_endings(true)
+            .read_into::<String>(Cursor::new("# lorem\n# ipsum\ndolor\nsit\r\n"))
+            .unwrap_err();
+        assert_eq!(e.to_string(), "line 4: line ends with CRLF, but most lines end with LF");
+    }
+
+    #[test]
+    fn read_joined() {
+        let options = ReadLinesOptions::new().endings(Endings::Clip).whitespace(Whitespace::Trim);
+        for path in PATHS {
+            assert_eq!(options.read_path_joined(path, "\n").unwrap(), "lorem\nipsum", "{}", path);
+        }
+        assert_eq!(options.read_joined(Cursor::new(""), ", ").unwrap(), "");
+        assert_eq!(options.read_joined(Cursor::new("lorem"), ", ").unwrap(), "lorem");
+        assert_eq!(options.skip_blank(true).read_joined(Cursor::new("\nlorem\n\nipsum\n\n"), ", ").unwrap(), "lorem, ipsum");
+        let e = ReadLinesOptions::new().read_path_joined("missing.txt", ", ").unwrap_err();
+        assert!(matches!(e, Error::Open { .. }));
+    }
+
+    #[test]
+    fn read_parsed() {
+        let options = ReadLinesOptions::new()
+            .endings(Endings::Clip)
+            .whitespace(Whitespace::Trim)
+            .skip_blank(true)
+            .comment_prefixes(&["#"]);
+        let numbers: Vec<u64> = options.read_parsed(Cursor::new(" 1 \r\n\n# lorem\n2")).unwrap();
+        assert_eq!(numbers, vec![1, 2]);
+        let e = options.read_parsed::<u64>(Cursor::new("1\n\n# lorem\nipsum\n")).unwrap_err();
+        match e {
+            ParseLinesError::Parse { path, line, content, source } => {
+                assert_eq!(path, None);
+                assert_eq!(line, 4);
+                assert_eq!(content, "ipsum");
+                assert_eq!(source, "ipsum".parse::<u64>().unwrap_err());
+            }
+            ParseLinesError::Read(e) => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn read_parsed_with_strict_endings() {
+        let options = ReadLinesOptions::new().endings(Endings::Clip).strict_endings(true);
+        let e = options.read_parsed::<u64>(Cursor::new("1\n2\r\n3\n")).unwrap_err();
+        assert!(matches!(e, ParseLinesError::Read(_)));
+        let numbers: Vec<u64> = options.read_parsed(Cursor::new("1\n2\n3")).unwrap();
+        assert_eq!(numbers, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn read_path_parsed() {
+        let e = ReadLinesOptions::new()
+            .endings(Endings::Clip)
+            .read_path_parsed::<u64>("example.txt")
+            .unwrap_err();
+        assert_eq!(e.to_string(), "example.txt:1: cannot parse \"lorem\": invalid digit found in string");
+    }
+
+    #[test]
+    fn lossy() {
+        let options = ReadLinesOptions::new().endings(Endings::Clip).whitespace(Whitespace::Trim);
+        assert!(options.read_path_into::<Vec<String>>("example-with-latin1.txt").is_err());
+        let strings: Vec<String> = options.lossy(true).read_path_into("example-with-latin1.txt").unwrap();
+        assert_eq!(strings, vec!["lorem", "ipsum caf\u{FFFD}"]);
+    }
+
+    #[test]
+    fn read_bytes_into() {
+        let options = ReadLinesOptions::new().terminators(LineTerminators::DEFAULT | LineTerminators::CR);
+        let lines: Vec<Vec<u8>> = options.read_path_bytes_into("example-with-cr.txt").unwrap();
+        assert_eq!(lines, vec![b"lorem\r".to_vec(), b"ipsum\r".to_vec()]);
+        let options = options.endings(Endings::Normalize(NewlineStyle::Lf)).whitespace(Whitespace::TrimEnd);
+        let lines: Vec<Vec<u8>> = options.read_bytes_into(Cursor::new(b" caf\xe9 \r\xa0\r\nipsum")).unwrap();
+        assert_eq!(lines, vec![b" caf\xe9\n".to_vec(), b"\xa0\n".to_vec(), b"ipsum".to_vec()]);
+        let lines: Vec<Vec<u8>> = options.clone().take(1).read_bytes_into(Cursor::new("lorem\ripsum\r")).unwrap();
+        assert_eq!(lines, vec![b"lorem\n".to_vec()]);
+        let lines: Vec<Vec<u8>> = options.clone().until_blank(true).read_bytes_into(Cursor::new("lorem\r\ripsum\r")).unwrap();
+        assert_eq!(lines, vec![b"lorem\n".to_vec()]);
+        let options = options.limits(Limits::new().max_line_bytes(4, LimitAction::Error));
+        let e = options.read_path_bytes_into::<Vec<Vec<u8>>>("example-with-cr.txt").unwrap_err();
+        assert_eq!(e.to_string(), "example-with-cr.txt:1: line is longer than 4 bytes");
+    }
+
+    #[test]
+    fn read_last_into() {
+        let options = ReadLinesOptions::new().terminators(LineTerminators::DEFAULT | LineTerminators::CR);
+        let strings: Vec<String> = options.read_path_last_into("example-with-cr.txt", 1).unwrap();
+        assert_eq!(strings, vec!["ipsum\r"]);
+        let options = options.endings(Endings::Normalize(NewlineStyle::Lf)).whitespace(Whitespace::Trim).skip_blank(true);
+        let strings: Vec<String> = options.read_last_into(Cursor::new("lorem\r ipsum \r\r\ndolor"), 3).unwrap();
+        assert_eq!(strings, vec!["ipsum\n", "dolor"]);
+        assert!(options.read_last_into::<Vec<String>>(Cursor::new(b"caf\xe9\n"), 1).is_err());
+        let strings: Vec<String> = options.clone().lossy(true).read_last_into(Cursor::new(b"caf\xe9\n"), 1).unwrap();
+        assert_eq!(strings, vec!["caf\u{FFFD}\n"]);
+        let options = options.limits(Limits::new().max_line_bytes(4, LimitAction::Error));
+        let e = options.read_path_last_into::<Vec<String>>("example-with-cr.txt", 1).unwrap_err();
+        assert!(matches!(e, Error::LimitExceeded { limit: Limit::LineBytes(4), .. }));
+        assert!(e.to_string().starts_with("example-with-cr.txt"));
+    }
+
+    #[test]
+    fn limits() {
+        let options = ReadLinesOptions::new().endings(Endings::Clip).limits(Limits::new().max_lines(1, LimitAction::Error));
+        let e = options.read_path_into::<Vec<String>>("example.txt").unwrap_err();
+        assert_eq!(e.to_string(), "example.txt:2: more than 1 lines");
+        let options = options.limits(Limits::new().max_total_bytes(8, LimitAction::Truncate));
+        let strings: Vec<String> = options.read_path_into("example.txt").unwrap();
+        assert_eq!(strings, vec!["lorem"]);
+    }
+
+    #[test]
+    fn limits_with_generated_huge_line() {
+        let bytes = [b"lorem\n".to_vec(), vec![b' '; 10_000_000], b"ipsum\n".to_vec()].concat();
+        let options = ReadLinesOptions::new().limits(Limits::new().max_line_bytes(1024, LimitAction::Error));
+        let e = options.read_into::<Vec<String>>(&bytes[..]).unwrap_err();
+        assert_eq!(e.to_string(), "line 2: line is longer than 1024 bytes");
+        let options = ReadLinesOptions::new()
+            .whitespace(Whitespace::Trim)
+            .skip_blank(true)
+            .limits(Limits::new().max_line_bytes(1024, LimitAction::Truncate));
+        let strings: Vec<String> = options.read_into(&bytes[..]).unwrap();
+        assert_eq!(strings, vec!["lorem\n"]);
+    }
+
+    #[test]
+    fn decode() {
+        let options = ReadLinesOptions::new().endings(Endings::Clip);
+        for path in ["example.txt", "example-with-utf8-bom.txt", "example-with-utf16le.txt", "example-with-utf16be.txt"] {
+            let strings: Vec<String> = options.clone().decode(true).read_path_into(path).unwrap();
+            assert_eq!(strings, vec!["lorem", "ipsum"], "{}", path);
+        }
+        let strings: Vec<String> = options.read_path_into("example-with-utf8-bom.txt").unwrap();
+        assert_eq!(strings, vec!["\u{FEFF}lorem", "ipsum"]);
+    }
+
+    #[test]
+    fn decode_counts_decoded_bytes() {
+        let options = ReadLinesOptions::new().decode(true);
+        let e = options.read_into::<String>(&b"\xEF\xBB\xBFab\xff\n"[..]).unwrap_err();
+        assert!(matches!(e, Error::InvalidUtf8 { line: Some(1), byte_offset: 2, .. }));
+        let bytes: Vec<u8> = "é\nlorem\n".encode_utf16().flat_map(|x| x.to_le_bytes()).collect();
+        let lines = options.encoding(Encoding::Utf16Le).read_lines(&bytes[..]).unwrap();
+        assert_eq!(lines[1].byte_offset, 3);
+    }
+
+    #[test]
+    fn encoding() {
+        let bytes: Vec<u8> = "lorem\r\nipsum".encode_utf16().flat_map(|x| x.to_be_bytes()).collect();
+        let strings: Vec<String> = ReadLinesOptions::new()
+            .encoding(Encoding::Utf16Be)
+            .read_into(Cursor::new(bytes))
+            .unwrap();
+        assert_eq!(strings, vec!["lorem\r\n", "ipsum"]);
+    }
+
+    #[test]
+    fn skip_blank() {
+        let strings: Vec<String> = ReadLinesOptions::new()
+            .skip_blank(true)
+            .read_into(Cursor::new("lorem\n\n \t\r\nipsum\n"))
+            .unwrap();
+        assert_eq!(strings, vec!["lorem\n", "ipsum\n"]);
+    }
+
+    #[test]
+    fn terminators() {
+        let options = ReadLinesOptions::new()
+            .endings(Endings::Normalize(NewlineStyle::Lf))
+            .terminators(LineTerminators::ALL);
+        let string: String = options.read_into(Cursor::new("lorem\ripsum\u{2029}dolor\r\n")).unwrap();
+        assert_eq!(string, "lorem\nipsum\ndolor\n");
+    }
+
+    #[test]
+    fn strict_endings() {
+        let options = ReadLinesOptions::new().strict_endings(true).endings(Endings::Clip);
+        for path in PATHS {
+            let strings: Vec<String> = options.read_path_into(path).unwrap();
+            assert_eq!(strings.len(), 2);
+        }
+        let e = options.read_path_into::<String>("example-with-mixed-endings.txt").unwrap_err();
+        assert_eq!(e.kind(), ErrorKind::InvalidData);
+        assert!(matches!(e, Error::MixedEndings { line: 2, found: LineEnding::CrLf, expected: LineEnding::Lf, .. }));
+        assert_eq!(e.to_string(), "example-with-mixed-endings.txt:2: line ends with CRLF, but most lines end with LF");
+    }
+
+    #[test]
+    fn strict_endings_allows_missing_last_line_end() {
+        let strings: Vec<String> = ReadLinesOptions::new()
+            .strict_endings(true)
+            .read_into(Cursor::new("lorem\r\nipsum\r\ndolor"))
+            .unwrap();
+        assert_eq!(strings, vec!["lorem\r\n", "ipsum\r\n", "dolor"]);
+    }
+
+    #[test]
+    fn take() {
+        let mut cursor = Cursor::new("lorem\ripsum\rdolor\r");
+        let options = ReadLinesOptions::new().terminators(LineTerminators::CR).endings(Endings::Clip).take(2);
+        let strings: Vec<String> = options.read_into(&mut cursor).unwrap();
+        assert_eq!(strings, vec!["lorem", "ipsum"]);
+        assert_eq!(cursor.position(), 12);
+        let strings: Vec<String> = options.read_into(&mut cursor).unwrap();
+        assert_eq!(strings, vec!["dolor"]);
+    }
+
+    #[test]
+    fn take_with_limits() {
+        let options = ReadLinesOptions::new().take(2).limits(Limits::new().max_lines(1, LimitAction::Error));
+        let e = options.read_into::<Vec<String>>(Cursor::new("lorem\nipsum\n")).unwrap_err();
+        assert_eq!(e.to_string(), "line 2: more than 1 lines");
+        let options = ReadLinesOptions::new().take(1).limits(Limits::new().max_lines(2, LimitAction::Error));
+        let strings: Vec<String> = options.read_into(Cursor::new("lorem\nipsum\ndolor\n")).unwrap();
+        assert_eq!(strings, vec!["lorem\n"]);
+    }
+
+    #[test]
+    fn take_with_line_bytes_limit() {
+        let text = "lorem\nipsum dolor\nsit\n";
+        let options = ReadLinesOptions::new().take(2).limits(Limits::new().max_line_bytes(5, LimitAction::Error));
+        let e = options.read_into::<Vec<String>>(Cursor::new(text)).unwrap_err();
+        assert!(matches!(e, Error::LimitExceeded { line: Some(2), limit: Limit::LineBytes(5), .. }));
+        let options = options.limits(Limits::new().max_line_bytes(5, LimitAction::Truncate));
+        let strings: Vec<String> = options.read_into(Cursor::new(text)).unwrap();
+        assert_eq!(strings, vec!["lorem\n", "ipsum\n"]);
+    }
+
+    #[test]
+    fn take_with_dedent() {
+        let mut cursor = Cursor::new("  lorem\n    ipsum\ndolor\n");
+        let string: String = ReadLinesOptions::new().dedent(true).take(2).read_into(&mut cursor).unwrap();
+        assert_eq!(string, "lorem\n  ipsum\n");
+        assert_eq!(cursor.position(), 18);
+    }
+
+    #[test]
+    fn until_blank() {
+        let mut cursor = Cursor::new("lorem\r\n\r\nipsum\u{2029}\u{2029}dolor\n");
+        let options = ReadLinesOptions::new().terminators(LineTerminators::ALL).until_blank(true);
+        let string: String = options.read_into(&mut cursor).unwrap();
+        assert_eq!(string, "lorem\r\n");
+        let string: String = options.read_into(&mut cursor).unwrap();
+        assert_eq!(string, "ipsum\u{2029}");
+        let string: String = options.read_into(&mut cursor).unwrap();
+        assert_eq!(string, "dolor\n");
+    }
+
+    #[test]
+    fn until_blank_with_strict_endings() {
+        let mut cursor = Cursor::new("lorem\nipsum\n\ndolor\r\n");
+        let options = ReadLinesOptions::new().strict_endings(true).until_blank(true);
+        let string: String = options.read_into(&mut cursor).unwrap();
+        assert_eq!(string, "lorem\nipsum\n");
+        assert_eq!(cursor.position(), 13);
+    }
+
+    #[test]
+    fn until_blank_with_limits() {
+        let options = ReadLinesOptions::new().until_blank(true).limits(Limits::new().max_line_bytes(5, LimitAction::Error));
+        let e = options.read_into::<String>(Cursor::new("lorem\nipsum dolor\n\n")).unwrap_err();
+        assert_eq!(e.to_string(), "line 2: line is longer than 5 bytes");
+    }
+
+    #[test]
+    fn read_lines() {
+        let lines = ReadLinesOptions::new()
+            .terminators(LineTerminators::ALL)
+            .whitespace(Whitespace::Trim)
+            .skip_blank(true)
+            .endings(Endings::Clip)
+            .read_lines(Cursor::new(" lorem \r\n\n\tipsum\u{85}"))
+            .unwrap();
+        assert_eq!(lines, vec![
+            Line { number: 1, byte_offset: 0, content: String::from("lorem"), ending: LineEnding::CrLf },
+            Line { number: 3, byte_offset: 10, content: String::from("ipsum"), ending: LineEnding::Nel },
+        ]);
+    }
+
+    #[test]
+    fn read_path_lines() {
+        let lines = ReadLinesOptions::new().read_path_lines("example-with-crlf.txt").unwrap();
+        assert_eq!(crate::line::to_original_bytes(&lines), ::std::fs::read("example-with-crlf.txt").unwrap());
+        let e = ReadLinesOptions::new()
+            .limits(Limits::new().max_line_bytes(4, LimitAction::Error))
+            .read_path_lines("example.txt")
+            .unwrap_err();
+        assert_eq!(e.to_string(), "example.txt:1: line is longer than 4 bytes");
+    }
+
+    #[test]
+    fn read_line_ending_report() {
+        let options = ReadLinesOptions::new().terminators(LineTerminators::DEFAULT | LineTerminators::CR);
+        let report = options.read_path_line_ending_report("example-with-mixed-endings.txt").unwrap();
+        assert_eq!((report.lf, report.crlf, report.dominant), (2, 1, Some(LineEnding::Lf)));
+        let report = options.clone().until_blank(true).read_line_ending_report(Cursor::new("lorem\r\r\nipsum\n")).unwrap();
+        assert_eq!((report.lf, report.crlf, report.cr), (0, 0, 1));
+    }
+
+    #[test]
+    fn read_line_ending_report_with_limits() {
+        let bytes = [b"lorem\n".to_vec(), vec![b' '; 10_000_000], b"\r\nipsum\r\n".to_vec()].concat();
+        let options = ReadLinesOptions::new().limits(Limits::new().max_line_bytes(1024, LimitAction::Error));
+        let e = options.read_line_ending_report(&bytes[..]).unwrap_err();
+        assert!(matches!(e, Error::LimitExceeded { line: Some(2), limit: Limit::LineBytes(1024), .. }));
+        let options = options.limits(Limits::new().max_line_bytes(1024, LimitAction::Truncate));
+        let report = options.read_line_ending_report(&bytes[..]).unwrap();
+        assert_eq!((report.lf, report.crlf), (1, 2));
+        let options = options.limits(Limits::new().max_lines(1, LimitAction::Error));
+        let e = options.read_path_line_ending_report("example.txt").unwrap_err();
+        assert_eq!(e.to_string(), "example.txt:2: more than 1 lines");
+        let options = options.limits(Limits::new().max_total_bytes(8, LimitAction::Error));
+        let e = options.read_line_ending_report(&bytes[..]).unwrap_err();
+        assert!(matches!(e, Error::LimitExceeded { limit: Limit::TotalBytes(8), .. }));
+    }
+
+    #[test]
+    fn strict_endings_with_cr() {
+        let e = ReadLinesOptions::new()
+            .strict_endings(true)
+            .terminators(LineTerminators::DEFAULT | LineTerminators::CR)
+            .read_into::<String>(Cursor::new("lorem\ripsum\r\ndolor\rsit\r"))
+            .unwrap_err();
+        assert_eq!(e.to_string(), "line 2: line ends with CRLF, but most lines end with CR");
+    }
+
+    #[test]
+    fn strict_endings_with_unicode_dominant() {
+        let options = ReadLinesOptions::new().terminators(LineTerminators::ALL).strict_endings(true);
+        let e = options.read_into::<Vec<String>>(Cursor::new("a\u{2028}b\u{2028}c\u{2028}d\n")).unwrap_err();
+        assert!(matches!(e, Error::MixedEndings { line: 4, found: LineEnding::Lf, expected: LineEnding::Ls, .. }));
+        let report = options.read_line_ending_report(Cursor::new("a\u{85}b\u{85}c\n")).unwrap();
+        assert_eq!((report.nel, report.lf, report.dominant), (2, 1, Some(LineEnding::Nel)));
+    }
+
+}
@@ -0,0 +1,143 @@
+//! Errors for parsing each line into a typed value.
+
+use std::path::Path;
+use std::path::PathBuf;
+use crate::error::Error;
+
+/// An error reading lines, or parsing a line into a typed value.
+///
+/// ```
+/// use read_lines_into::parse_lines_error::ParseLinesError;
+/// use read_lines_into::traits::*;
+///
+/// match "example.txt".read_lines_into_vec_parsed::<u64>() {
+///     Err(ParseLinesError::Parse { path, line, content, source }) => {
+///         assert_eq!(line, 1);
+///         assert_eq!(content, "lorem");
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Debug)]
+pub enum ParseLinesError<E> {
+    /// Opening or reading failed.
+    Read(Error),
+    /// Parsing a line failed.
+    Parse {
+        /// The path of the file, if known.
+        path: Option<PathBuf>,
+        /// The line number, starting at 1.
+        line: usize,
+        /// The line content, as given to `FromStr`.
+        content: String,
+        /// The error from `FromStr`.
+        source: E,
+    },
+}
+
+impl<E> ParseLinesError<E> {
+
+    /// Set the path of the file, if the error doesn't have one yet.
+    pub fn with_path(self, path: impl AsRef<Path>) -> Self {
+        match self {
+            ParseLinesError::Read(e) => ParseLinesError::Read(e.with_path(path)),
+            ParseLinesError::Parse { path: None, line, content, source } => {
+                ParseLinesError::Parse { path: Some(path.as_ref().to_path_buf()), line, content, source }
+            }
+            x => x,
+        }
+    }
+
+}
+
+impl<E: ::std::fmt::Display> ::std::fmt::Display for ParseLinesError<E> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        match self {
+            ParseLinesError::Read(e) => write!(f, "{}", e),
+            ParseLinesError::Parse { path: Some(path), line, content, source } => {
+                write!(f, "{}:{}: cannot parse {:?}: {}", path.display(), line, content, source)
+            }
+            ParseLinesError::Parse { path: None, line, content, source } => {
+                write!(f, "line {}: cannot parse {:?}: {}", line, content, source)
+            }
+        }
+    }
+}
+
+impl<E: ::std::error::Error + 'static> ::std::error::Error for ParseLinesError<E> {
+    fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+        match self {
+            ParseLinesError::Read(e) => Some(e),
+            ParseLinesError::Parse { source, .. } => Some(source),
+        }
+    }
+}
+
+impl<E> From<Error> for ParseLinesError<E> {
+    fn from(e: Error) -> Self {
+        ParseLinesError::Read(e)
+    }
+}
+
+/// Convert into an `Error`, which boxes the parse error.
+impl<E: ::std::error::Error + Send + Sync + 'static> From<ParseLinesError<E>> for Error {
+    fn from(e: ParseLinesError<E>) -> Self {
+        match e {
+            ParseLinesError::Read(e) => e,
+            ParseLinesError::Parse { path, line, content, source } => {
+                Error::Parse { path, line, content, source: Box::new(source) }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::error::Error as _;
+
+    fn parse_error() -> ParseLinesError<::std::num::ParseIntError> {
+        ParseLinesError::Parse {
+            path: None,
+            line: 2,
+            content: String::from("lorem"),
+            source: "lorem".parse::<u64>().unwrap_err(),
+        }
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            parse_error().to_string(),
+            "line 2: cannot parse \"lorem\": invalid digit found in string"
+        );
+        assert_eq!(
+            parse_error().with_path("example.txt").to_string(),
+            "example.txt:2: cannot parse \"lorem\": invalid digit found in string"
+        );
+    }
+
+    #[test]
+    fn source() {
+        assert_eq!(parse_error().source().unwrap().to_string(), "invalid digit found in string");
+        let e: ParseLinesError<::std::num::ParseIntError> = Error::from(::std::io::Error::other("ipsum")).into();
+        assert_eq!(e.source().unwrap().to_string(), "ipsum");
+    }
+
+    #[test]
+    fn with_path_and_read_error() {
+        let e: ParseLinesError<::std::num::ParseIntError> = Error::from(::std::io::Error::other("ipsum")).into();
+        assert_eq!(e.with_path("example.txt").to_string(), "example.txt: ipsum");
+    }
+
+    #[test]
+    fn into_error() {
+        let e = Error::from(parse_error().with_path("example.txt"));
+        assert_eq!(e.to_string(), "example.txt:2: cannot parse \"lorem\": invalid digit found in string");
+        match e {
+            Error::Parse { source, .. } => assert!(source.is::<::std::num::ParseIntError>()),
+            e => panic!("{:?}", e),
+        }
+    }
+
+}
@@ -0,0 +1,120 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use crate::follow::Follow;
+use crate::traits::*;
+
+/// Follow a file as it grows, like `tail -F`; keep each line end `\n` or `\r\n`.
+/// 
+/// ```
+/// use std::time::Duration;
+/// use read_lines_into::path::follow;
+/// 
+/// for line in follow("example.txt").idle_timeout(Duration::from_millis(50)) {
+///     let string: String = line.unwrap();
+/// }
+/// ```
+/// 
+/// Any error is yielded as an item; the file doesn't need to exist yet.
+/// 
+pub fn follow(path: impl AsRef<Path>) -> Follow {
+    Follow::new(path)
+}
+
+impl FollowOnRefSelf for Path {
+
+    /// Follow a file as it grows, like `tail -F`; keep each line end `\n` or `\r\n`.
+    /// 
+    /// ```
+    /// use std::path::Path;
+    /// use std::time::Duration;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let path = Path::new("example.txt");
+    /// for line in path.follow().idle_timeout(Duration::from_millis(50)) {
+    ///     let string: String = line.unwrap();
+    /// }
+    /// ```
+    /// 
+    /// Any error is yielded as an item; the file doesn't need to exist yet.
+    /// 
+    fn follow(&self) -> Follow {
+        follow(self)
+    }
+
+}
+
+impl FollowOnRefSelf for str {
+
+    fn follow(&self) -> Follow {
+        follow(self)
+    }
+
+}
+
+impl FollowOnRefSelf for String {
+
+    fn follow(&self) -> Follow {
+        follow(self)
+    }
+
+}
+
+impl FollowOnRefSelf for OsStr {
+
+    fn follow(&self) -> Follow {
+        follow(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn sut(path: &(impl FollowOnRefSelf + ?Sized)) -> Vec<String> {
+        path.follow()
+            .poll_interval(Duration::from_millis(5))
+            .idle_timeout(Duration::from_millis(20))
+            .collect::<crate::Result<Vec<String>>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn with_lf() {
+        assert_eq!(
+            sut(Path::new("example.txt")),
+            vec![String::from("lorem\n"), String::from("ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        assert_eq!(
+            sut(Path::new("example-with-crlf.txt")),
+            vec![String::from("lorem\r\n"), String::from("ipsum\r\n")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        assert_eq!(
+            sut(Path::new("example-with-indent.txt")),
+            vec![String::from("    lorem\n"), String::from("    ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_str() {
+        assert_eq!(
+            sut("example.txt"),
+            vec![String::from("lorem\n"), String::from("ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_missing_file() {
+        assert!(sut("missing.txt").is_empty());
+    }
+
+}
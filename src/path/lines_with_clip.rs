@@ -0,0 +1,119 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use std::fs::File;
+use std::io::BufReader;
+use crate::iter::LinesWithClip;
+use crate::error::Error;
+use crate::traits::*;
+
+/// Iterate lines lazily, one String at a time; clip each line end `\n` or `\r\n`.
+/// 
+/// ```
+/// use read_lines_into::path::lines_with_clip;
+/// 
+/// for line in lines_with_clip("example.txt").unwrap() {
+///     let string: String = line.unwrap();
+/// }
+/// ```
+/// 
+/// Any error opening the file will return immediately;
+/// any error reading is yielded as an item.
+/// 
+pub fn lines_with_clip(path: impl AsRef<Path>) -> crate::Result<LinesWithClip<BufReader<File>>> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    Ok(BufReader::new(file).lines_with_clip())
+}
+
+impl LinesWithClipOnRefSelf for Path {
+
+    /// Iterate lines lazily, one String at a time; clip each line end `\n` or `\r\n`.
+    /// 
+    /// ```
+    /// use std::path::Path;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let path = Path::new("example.txt");
+    /// for line in path.lines_with_clip().unwrap() {
+    ///     let string: String = line.unwrap();
+    /// }
+    /// ```
+    /// 
+    /// Any error opening the file will return immediately;
+    /// any error reading is yielded as an item.
+    /// 
+    fn lines_with_clip(&self) -> crate::Result<LinesWithClip<BufReader<File>>> {
+        lines_with_clip(self)
+    }
+
+}
+
+impl LinesWithClipOnRefSelf for str {
+
+    fn lines_with_clip(&self) -> crate::Result<LinesWithClip<BufReader<File>>> {
+        lines_with_clip(self)
+    }
+
+}
+
+impl LinesWithClipOnRefSelf for String {
+
+    fn lines_with_clip(&self) -> crate::Result<LinesWithClip<BufReader<File>>> {
+        lines_with_clip(self)
+    }
+
+}
+
+impl LinesWithClipOnRefSelf for OsStr {
+
+    fn lines_with_clip(&self) -> crate::Result<LinesWithClip<BufReader<File>>> {
+        lines_with_clip(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_lf() {
+        let path = Path::new("example.txt");
+        assert_eq!(
+            path.lines_with_clip().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let path = Path::new("example-with-crlf.txt");
+        assert_eq!(
+            path.lines_with_clip().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let path = Path::new("example-with-indent.txt");
+        assert_eq!(
+            path.lines_with_clip().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("    lorem"), String::from("    ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_str() {
+        assert_eq!(
+            "example.txt".lines_with_clip().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_missing_file() {
+        assert!(lines_with_clip("missing.txt").is_err());
+    }
+
+}
@@ -0,0 +1,119 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use std::fs::File;
+use std::io::BufReader;
+use crate::iter::LinesWithEndings;
+use crate::error::Error;
+use crate::traits::*;
+
+/// Iterate lines lazily, one String at a time; keep each line end `\n` or `\r\n`.
+/// 
+/// ```
+/// use read_lines_into::path::lines_with_endings;
+/// 
+/// for line in lines_with_endings("example.txt").unwrap() {
+///     let string: String = line.unwrap();
+/// }
+/// ```
+/// 
+/// Any error opening the file will return immediately;
+/// any error reading is yielded as an item.
+/// 
+pub fn lines_with_endings(path: impl AsRef<Path>) -> crate::Result<LinesWithEndings<BufReader<File>>> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    Ok(BufReader::new(file).lines_with_endings())
+}
+
+impl LinesWithEndingsOnRefSelf for Path {
+
+    /// Iterate lines lazily, one String at a time; keep each line end `\n` or `\r\n`.
+    /// 
+    /// ```
+    /// use std::path::Path;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let path = Path::new("example.txt");
+    /// for line in path.lines_with_endings().unwrap() {
+    ///     let string: String = line.unwrap();
+    /// }
+    /// ```
+    /// 
+    /// Any error opening the file will return immediately;
+    /// any error reading is yielded as an item.
+    /// 
+    fn lines_with_endings(&self) -> crate::Result<LinesWithEndings<BufReader<File>>> {
+        lines_with_endings(self)
+    }
+
+}
+
+impl LinesWithEndingsOnRefSelf for str {
+
+    fn lines_with_endings(&self) -> crate::Result<LinesWithEndings<BufReader<File>>> {
+        lines_with_endings(self)
+    }
+
+}
+
+impl LinesWithEndingsOnRefSelf for String {
+
+    fn lines_with_endings(&self) -> crate::Result<LinesWithEndings<BufReader<File>>> {
+        lines_with_endings(self)
+    }
+
+}
+
+impl LinesWithEndingsOnRefSelf for OsStr {
+
+    fn lines_with_endings(&self) -> crate::Result<LinesWithEndings<BufReader<File>>> {
+        lines_with_endings(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_lf() {
+        let path = Path::new("example.txt");
+        assert_eq!(
+            path.lines_with_endings().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("lorem\n"), String::from("ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let path = Path::new("example-with-crlf.txt");
+        assert_eq!(
+            path.lines_with_endings().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("lorem\r\n"), String::from("ipsum\r\n")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let path = Path::new("example-with-indent.txt");
+        assert_eq!(
+            path.lines_with_endings().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("    lorem\n"), String::from("    ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_str() {
+        assert_eq!(
+            "example.txt".lines_with_endings().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("lorem\n"), String::from("ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_missing_file() {
+        assert!(lines_with_endings("missing.txt").is_err());
+    }
+
+}
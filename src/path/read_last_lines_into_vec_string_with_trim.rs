@@ -0,0 +1,112 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use std::fs::File;
+use crate::error::Error;
+use crate::traits::*;
+
+/// Read the last n lines into Vec<String>; trim each line.
+/// 
+/// This seeks backwards from the end, so it reads about as many
+/// bytes as the last `n` lines, rather than the whole source.
+/// 
+/// ```
+/// use read_lines_into::path::read_last_lines_into_vec_string_with_trim;
+/// 
+/// let strings: Vec<String> = read_last_lines_into_vec_string_with_trim("example.txt", 200).unwrap();
+/// ```
+/// 
+/// Any error will return immediately.
+/// 
+pub fn read_last_lines_into_vec_string_with_trim(path: impl AsRef<Path>, n: usize) -> crate::Result<Vec<String>> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    file.read_last_lines_into_vec_string_with_trim(n).map_err(|e| e.with_path(path))
+}
+
+impl ReadLastLinesIntoStringsWithTrimOnRefSelf for Path {
+
+    /// Read the last n lines into Vec<String>; trim each line.
+    /// 
+    /// This seeks backwards from the end, so it reads about as many
+    /// bytes as the last `n` lines, rather than the whole source.
+    /// 
+    /// ```
+    /// use std::path::Path;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let path = Path::new("example.txt");
+    /// let strings: Vec<String> = path.read_last_lines_into_vec_string_with_trim(200).unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_last_lines_into_vec_string_with_trim(&self, n: usize) -> crate::Result<Vec<String>> {
+        read_last_lines_into_vec_string_with_trim(self, n)
+    }
+
+}
+
+impl ReadLastLinesIntoStringsWithTrimOnRefSelf for str {
+
+    fn read_last_lines_into_vec_string_with_trim(&self, n: usize) -> crate::Result<Vec<String>> {
+        read_last_lines_into_vec_string_with_trim(self, n)
+    }
+
+}
+
+impl ReadLastLinesIntoStringsWithTrimOnRefSelf for String {
+
+    fn read_last_lines_into_vec_string_with_trim(&self, n: usize) -> crate::Result<Vec<String>> {
+        read_last_lines_into_vec_string_with_trim(self, n)
+    }
+
+}
+
+impl ReadLastLinesIntoStringsWithTrimOnRefSelf for OsStr {
+
+    fn read_last_lines_into_vec_string_with_trim(&self, n: usize) -> crate::Result<Vec<String>> {
+        read_last_lines_into_vec_string_with_trim(self, n)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_lf() {
+        let path = Path::new("example.txt");
+        assert_eq!(
+            path.read_last_lines_into_vec_string_with_trim(1).unwrap(),
+            vec![String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let path = Path::new("example-with-crlf.txt");
+        assert_eq!(
+            path.read_last_lines_into_vec_string_with_trim(1).unwrap(),
+            vec![String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let path = Path::new("example-with-indent.txt");
+        assert_eq!(
+            path.read_last_lines_into_vec_string_with_trim(1).unwrap(),
+            vec![String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_str() {
+        assert_eq!(
+            "example.txt".read_last_lines_into_vec_string_with_trim(1).unwrap(),
+            vec![String::from("ipsum")]
+        );
+    }
+
+}
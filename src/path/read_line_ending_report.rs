@@ -0,0 +1,99 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use std::fs::File;
+use crate::line_ending_report::LineEndingReport;
+use crate::error::Error;
+use crate::traits::*;
+
+/// Read lines into a LineEndingReport; count each line end `\n`, `\r\n`, or `\r`.
+/// 
+/// ```
+/// use read_lines_into::line_ending_report::LineEndingReport;
+/// use read_lines_into::path::read_line_ending_report;
+/// 
+/// let report: LineEndingReport = read_line_ending_report("example.txt").unwrap();
+/// ```
+/// 
+/// Any error will return immediately.
+/// 
+pub fn read_line_ending_report(path: impl AsRef<Path>) -> crate::Result<LineEndingReport> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    file.read_line_ending_report().map_err(|e| e.with_path(path))
+}
+
+impl ReadLineEndingReportOnRefSelf for Path {
+
+    /// Read lines into a LineEndingReport; count each line end `\n`, `\r\n`, or `\r`.
+    /// 
+    /// ```
+    /// use std::path::Path;
+    /// use read_lines_into::line_ending_report::LineEndingReport;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let path = Path::new("example.txt");
+    /// let report: LineEndingReport = path.read_line_ending_report().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_line_ending_report(&self) -> crate::Result<LineEndingReport> {
+        read_line_ending_report(self)
+    }
+
+}
+
+impl ReadLineEndingReportOnRefSelf for str {
+
+    fn read_line_ending_report(&self) -> crate::Result<LineEndingReport> {
+        read_line_ending_report(self)
+    }
+
+}
+
+impl ReadLineEndingReportOnRefSelf for String {
+
+    fn read_line_ending_report(&self) -> crate::Result<LineEndingReport> {
+        read_line_ending_report(self)
+    }
+
+}
+
+impl ReadLineEndingReportOnRefSelf for OsStr {
+
+    fn read_line_ending_report(&self) -> crate::Result<LineEndingReport> {
+        read_line_ending_report(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::line_ending::LineEnding;
+
+    #[test]
+    fn with_lf() {
+        let path = Path::new("example.txt");
+        assert_eq!(path.read_line_ending_report().unwrap().dominant, Some(LineEnding::Lf));
+    }
+
+    #[test]
+    fn with_crlf() {
+        let path = Path::new("example-with-crlf.txt");
+        assert_eq!(path.read_line_ending_report().unwrap().dominant, Some(LineEnding::CrLf));
+    }
+
+    #[test]
+    fn with_indent() {
+        let path = Path::new("example-with-indent.txt");
+        assert_eq!(path.read_line_ending_report().unwrap().dominant, Some(LineEnding::Lf));
+    }
+
+    #[test]
+    fn with_mixed_endings() {
+        let report = "example-with-mixed-endings.txt".read_line_ending_report().unwrap();
+        assert_eq!((report.lf, report.crlf, report.is_mixed), (2, 1, true));
+    }
+
+}
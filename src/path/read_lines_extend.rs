@@ -0,0 +1,123 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use std::fs::File;
+use crate::error::Error;
+use crate::traits::*;
+
+/// Read lines and append them to an existing collection of strings,
+/// such as Vec<String> or HashSet<String>; clip each line end, as in `read_lines_into`.
+/// 
+/// ```
+/// use read_lines_into::path::read_lines_extend;
+/// 
+/// let mut strings: Vec<String> = vec![String::from("header")];
+/// read_lines_extend("example.txt", &mut strings).unwrap();
+/// ```
+/// 
+/// Any error will return immediately, and the lines
+/// before the error will already be in the collection.
+/// 
+pub fn read_lines_extend(path: impl AsRef<Path>, collection: &mut impl Extend<String>) -> crate::Result<()> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    file.read_lines_extend(collection).map_err(|e| e.with_path(path))
+}
+
+impl ReadLinesExtendOnRefSelf for Path {
+
+    /// Read lines and append them to an existing collection of strings,
+    /// such as Vec<String> or HashSet<String>; clip each line end, as in `read_lines_into`.
+    /// 
+    /// ```
+    /// use std::path::Path;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let mut strings: Vec<String> = vec![String::from("header")];
+    /// let path = Path::new("example.txt");
+    /// path.read_lines_extend(&mut strings).unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately, and the lines
+    /// before the error will already be in the collection.
+    /// 
+    fn read_lines_extend(&self, collection: &mut impl Extend<String>) -> crate::Result<()> {
+        read_lines_extend(self, collection)
+    }
+
+}
+
+impl ReadLinesExtendOnRefSelf for str {
+
+    fn read_lines_extend(&self, collection: &mut impl Extend<String>) -> crate::Result<()> {
+        read_lines_extend(self, collection)
+    }
+
+}
+
+impl ReadLinesExtendOnRefSelf for String {
+
+    fn read_lines_extend(&self, collection: &mut impl Extend<String>) -> crate::Result<()> {
+        read_lines_extend(self, collection)
+    }
+
+}
+
+impl ReadLinesExtendOnRefSelf for OsStr {
+
+    fn read_lines_extend(&self, collection: &mut impl Extend<String>) -> crate::Result<()> {
+        read_lines_extend(self, collection)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collection() -> Vec<String> {
+        vec![String::from("header")]
+    }
+
+    #[test]
+    fn with_lf() {
+        let path = Path::new("example.txt");
+        let mut strings = collection();
+        path.read_lines_extend(&mut strings).unwrap();
+        assert_eq!(
+            strings,
+            vec![String::from("header"), String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let path = Path::new("example-with-crlf.txt");
+        let mut strings = collection();
+        path.read_lines_extend(&mut strings).unwrap();
+        assert_eq!(
+            strings,
+            vec![String::from("header"), String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let path = Path::new("example-with-indent.txt");
+        let mut strings = collection();
+        path.read_lines_extend(&mut strings).unwrap();
+        assert_eq!(
+            strings,
+            vec![String::from("header"), String::from("    lorem"), String::from("    ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_str_and_string_and_os_str() {
+        let mut string = String::new();
+        "example.txt".read_lines_extend(&mut string).unwrap();
+        String::from("example.txt").read_lines_extend(&mut string).unwrap();
+        OsStr::new("example.txt").read_lines_extend(&mut string).unwrap();
+        assert_eq!(string, "loremipsum".repeat(3));
+    }
+
+}
@@ -0,0 +1,205 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use std::fs::File;
+use crate::checkpoint::Checkpoint;
+use crate::error::Error;
+use crate::traits::*;
+
+/// Read lines into Vec<String> from a checkpoint; keep each line end `\n` or `\r\n`,
+/// and return a checkpoint at the end of the last line.
+/// 
+/// A last line without a line ending is not read, because more of
+/// the line may be appended; the next read gets the whole line.
+/// 
+/// ```
+/// use read_lines_into::Checkpoint;
+/// use read_lines_into::path::read_lines_from_offset;
+/// 
+/// let (strings, checkpoint): (Vec<String>, Checkpoint) = read_lines_from_offset("example.txt", Checkpoint::new()).unwrap();
+/// ```
+/// 
+/// Any error will return immediately, including when the
+/// checkpoint is for a different file, or the file is truncated.
+/// An error's byte offset is from the start of the file, but its
+/// line number is from the checkpoint, i.e. line 1 is the first
+/// line after the checkpoint, because a checkpoint has no line count.
+/// 
+pub fn read_lines_from_offset(path: impl AsRef<Path>, checkpoint: Checkpoint) -> crate::Result<(Vec<String>, Checkpoint)> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    file.read_lines_from_offset(checkpoint).map_err(|e| e.with_path(path))
+}
+
+impl ReadLinesFromOffsetOnRefSelf for Path {
+
+    /// Read lines into Vec<String> from a checkpoint; keep each line end `\n` or `\r\n`,
+    /// and return a checkpoint at the end of the last line.
+    /// 
+    /// A last line without a line ending is not read, because more of
+    /// the line may be appended; the next read gets the whole line.
+    /// 
+    /// ```
+    /// use std::path::Path;
+    /// use read_lines_into::Checkpoint;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let path = Path::new("example.txt");
+    /// let (strings, checkpoint): (Vec<String>, Checkpoint) = path.read_lines_from_offset(Checkpoint::new()).unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately, including when the
+    /// checkpoint is for a different file, or the file is truncated.
+    /// An error's byte offset is from the start of the file, but its
+    /// line number is from the checkpoint, i.e. line 1 is the first
+    /// line after the checkpoint, because a checkpoint has no line count.
+    /// 
+    fn read_lines_from_offset(&self, checkpoint: Checkpoint) -> crate::Result<(Vec<String>, Checkpoint)> {
+        read_lines_from_offset(self, checkpoint)
+    }
+
+}
+
+impl ReadLinesFromOffsetOnRefSelf for str {
+
+    fn read_lines_from_offset(&self, checkpoint: Checkpoint) -> crate::Result<(Vec<String>, Checkpoint)> {
+        read_lines_from_offset(self, checkpoint)
+    }
+
+}
+
+impl ReadLinesFromOffsetOnRefSelf for String {
+
+    fn read_lines_from_offset(&self, checkpoint: Checkpoint) -> crate::Result<(Vec<String>, Checkpoint)> {
+        read_lines_from_offset(self, checkpoint)
+    }
+
+}
+
+impl ReadLinesFromOffsetOnRefSelf for OsStr {
+
+    fn read_lines_from_offset(&self, checkpoint: Checkpoint) -> crate::Result<(Vec<String>, Checkpoint)> {
+        read_lines_from_offset(self, checkpoint)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Limit;
+    use crate::limits::LimitAction;
+    use crate::limits::Limits;
+    use crate::line_terminators::LineTerminators;
+    use crate::options::*;
+    use crate::test_util::append;
+    use crate::test_util::temp_path;
+
+    #[test]
+    fn with_lf() {
+        let path = Path::new("example.txt");
+        let (strings, checkpoint) = path.read_lines_from_offset(Checkpoint::new()).unwrap();
+        assert_eq!(strings, vec![String::from("lorem\n"), String::from("ipsum\n")]);
+        assert_eq!(checkpoint.offset, 12);
+    }
+
+    #[test]
+    fn with_crlf() {
+        let path = Path::new("example-with-crlf.txt");
+        let (strings, checkpoint) = path.read_lines_from_offset(Checkpoint::new()).unwrap();
+        assert_eq!(strings, vec![String::from("lorem\r\n"), String::from("ipsum\r\n")]);
+        assert_eq!(checkpoint.offset, 14);
+    }
+
+    #[test]
+    fn with_indent() {
+        let path = Path::new("example-with-indent.txt");
+        let (strings, checkpoint) = path.read_lines_from_offset(Checkpoint::new()).unwrap();
+        assert_eq!(strings, vec![String::from("    lorem\n"), String::from("    ipsum\n")]);
+        assert_eq!(checkpoint.offset, 20);
+    }
+
+    #[test]
+    fn with_str() {
+        let (strings, _) = "example.txt".read_lines_from_offset(Checkpoint::new()).unwrap();
+        assert_eq!(strings, vec![String::from("lorem\n"), String::from("ipsum\n")]);
+    }
+
+    #[test]
+    fn resumes_in_batches_with_partial_line() {
+        let path = temp_path("batches");
+        append(&path, "lorem\nip");
+        let (strings, checkpoint) = path.read_lines_from_offset(Checkpoint::new()).unwrap();
+        assert_eq!(strings, vec![String::from("lorem\n")]);
+        assert_eq!(checkpoint.offset, 6);
+        append(&path, "sum\r\ndolor\n");
+        let checkpoint: Checkpoint = checkpoint.to_string().parse().unwrap();
+        let (strings, checkpoint) = path.read_lines_from_offset(checkpoint).unwrap();
+        assert_eq!(strings, vec![String::from("ipsum\r\n"), String::from("dolor\n")]);
+        assert_eq!(checkpoint.offset, 19);
+        let (strings, _) = path.read_lines_from_offset(checkpoint).unwrap();
+        assert!(strings.is_empty());
+        ::std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn with_invalid_utf8_after_checkpoint() {
+        let path = Path::new("example-with-latin1.txt");
+        let checkpoint = Checkpoint::of(&path.metadata().unwrap(), 6);
+        let e = path.read_lines_from_offset(checkpoint).unwrap_err();
+        assert_eq!(e.to_string(), "example-with-latin1.txt:1: stream did not contain valid UTF-8 at byte offset 15");
+    }
+
+    #[test]
+    fn with_options_and_last_line_longer_than_limit() {
+        let path = temp_path("limit");
+        append(&path, "lorem\ripsum\r");
+        append(&path, &"x".repeat(10_000));
+        let options = ReadLinesOptions::new()
+            .terminators(LineTerminators::DEFAULT | LineTerminators::CR)
+            .endings(Endings::Clip)
+            .limits(Limits::new().max_line_bytes(1024, LimitAction::Truncate));
+        let (strings, checkpoint) = options.read_path_from_offset(&path, Checkpoint::new()).unwrap();
+        assert_eq!(strings, vec!["lorem", "ipsum"]);
+        assert_eq!(checkpoint.offset, 12);
+        let options = options.limits(Limits::new().max_line_bytes(1024, LimitAction::Error));
+        let e = options.read_path_from_offset(&path, checkpoint).unwrap_err();
+        assert!(matches!(e, Error::LimitExceeded { line: Some(1), limit: Limit::LineBytes(1024), .. }));
+        append(&path, "\r");
+        let options = options.limits(Limits::new().max_line_bytes(1024, LimitAction::Truncate));
+        let (strings, checkpoint) = options.read_path_from_offset(&path, checkpoint).unwrap();
+        assert_eq!(strings, vec!["x".repeat(1024)]);
+        assert_eq!(checkpoint.offset, 10_013);
+        ::std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn with_truncated_file() {
+        let path = temp_path("truncated");
+        append(&path, "lorem\nipsum\n");
+        let (_, checkpoint) = path.read_lines_from_offset(Checkpoint::new()).unwrap();
+        ::std::fs::write(&path, "dolor\n").unwrap();
+        let e = path.read_lines_from_offset(checkpoint).unwrap_err();
+        assert!(matches!(e, Error::CheckpointMismatch { .. }));
+        assert!(e.to_string().starts_with(&format!("{}: file was replaced or truncated since checkpoint offset=12 ", path.display())));
+        ::std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn with_replaced_file() {
+        let path = temp_path("replaced");
+        let other = temp_path("replaced.new");
+        append(&path, "lorem\n");
+        let (_, checkpoint) = path.read_lines_from_offset(Checkpoint::new()).unwrap();
+        append(&other, "lorem\nipsum\n");
+        ::std::fs::rename(&other, &path).unwrap();
+        assert!(matches!(path.read_lines_from_offset(checkpoint), Err(Error::CheckpointMismatch { .. })));
+        ::std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn with_missing_file() {
+        assert!(matches!(read_lines_from_offset("missing.txt", Checkpoint::new()), Err(Error::Open { .. })));
+    }
+
+}
@@ -0,0 +1,127 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use std::fs::File;
+use crate::error::Error;
+use crate::traits::*;
+
+/// Read lines into any collection of strings, such as HashSet<String>,
+/// BTreeSet<String>, or VecDeque<String>; clip each line end.
+/// 
+/// Clipping means that lines that differ only in their line end,
+/// such as `lorem\n` and `lorem\r\n`, are the same string.
+/// To keep line ends, or to trim, use `read_lines_into_with_options`.
+/// 
+/// ```
+/// use std::collections::BTreeSet;
+/// use read_lines_into::path::read_lines_into;
+/// 
+/// let set = read_lines_into::<BTreeSet<String>>("example.txt").unwrap();
+/// ```
+/// 
+/// Any error will return immediately.
+/// 
+pub fn read_lines_into<C: FromIterator<String>>(path: impl AsRef<Path>) -> crate::Result<C> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    file.read_lines_into().map_err(|e| e.with_path(path))
+}
+
+impl ReadLinesIntoOnRefSelf for Path {
+
+    /// Read lines into any collection of strings, such as HashSet<String>,
+    /// BTreeSet<String>, or VecDeque<String>; clip each line end.
+    /// 
+    /// Clipping means that lines that differ only in their line end,
+    /// such as `lorem\n` and `lorem\r\n`, are the same string.
+    /// To keep line ends, or to trim, use `read_lines_into_with_options`.
+    /// 
+    /// ```
+    /// use std::collections::BTreeSet;
+    /// use std::path::Path;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let path = Path::new("example.txt");
+    /// let set = path.read_lines_into::<BTreeSet<String>>().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into<C: FromIterator<String>>(&self) -> crate::Result<C> {
+        read_lines_into(self)
+    }
+
+}
+
+impl ReadLinesIntoOnRefSelf for str {
+
+    fn read_lines_into<C: FromIterator<String>>(&self) -> crate::Result<C> {
+        read_lines_into(self)
+    }
+
+}
+
+impl ReadLinesIntoOnRefSelf for String {
+
+    fn read_lines_into<C: FromIterator<String>>(&self) -> crate::Result<C> {
+        read_lines_into(self)
+    }
+
+}
+
+impl ReadLinesIntoOnRefSelf for OsStr {
+
+    fn read_lines_into<C: FromIterator<String>>(&self) -> crate::Result<C> {
+        read_lines_into(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn with_lf() {
+        let path = Path::new("example.txt");
+        assert_eq!(
+            path.read_lines_into::<VecDeque<String>>().unwrap(),
+            VecDeque::from([String::from("lorem"), String::from("ipsum")])
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let path = Path::new("example-with-crlf.txt");
+        assert_eq!(
+            path.read_lines_into::<VecDeque<String>>().unwrap(),
+            VecDeque::from([String::from("lorem"), String::from("ipsum")])
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let path = Path::new("example-with-indent.txt");
+        assert_eq!(
+            path.read_lines_into::<VecDeque<String>>().unwrap(),
+            VecDeque::from([String::from("    lorem"), String::from("    ipsum")])
+        );
+    }
+
+    #[test]
+    fn with_str_and_string_and_os_str() {
+        assert_eq!(
+            "example.txt".read_lines_into::<String>().unwrap(),
+            "loremipsum"
+        );
+        assert_eq!(
+            String::from("example.txt").read_lines_into::<String>().unwrap(),
+            "loremipsum"
+        );
+        assert_eq!(
+            OsStr::new("example.txt").read_lines_into::<String>().unwrap(),
+            "loremipsum"
+        );
+    }
+
+}
@@ -1,7 +1,27 @@
+use std::ffi::OsStr;
 use std::path::Path;
 use std::fs::File;
+use crate::error::Error;
 use crate::traits::*;
 
+/// Read lines into String; keep each line end `\n` or `\r\n`.
+/// 
+/// ```
+/// use std::path::PathBuf;
+/// use read_lines_into::path::read_lines_into_string;
+/// 
+/// let string: String = read_lines_into_string("example.txt").unwrap();
+/// let string: String = read_lines_into_string(PathBuf::from("example.txt")).unwrap();
+/// ```
+/// 
+/// Any error will return immediately.
+/// 
+pub fn read_lines_into_string(path: impl AsRef<Path>) -> crate::Result<String> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    file.read_lines_into_string().map_err(|e| e.with_path(path))
+}
+
 impl ReadLinesIntoStringOnRefSelf for Path {
 
     /// Read lines into String; keep each line end `\n` or `\r\n`.
@@ -17,9 +37,40 @@ impl ReadLinesIntoStringOnRefSelf for Path {
     /// 
     /// Any error will return immediately.
     /// 
-    fn read_lines_into_string(&self) -> ::std::io::Result<String> {
-        let file = File::open(&self)?;
-        file.read_lines_into_string()
+    fn read_lines_into_string(&self) -> crate::Result<String> {
+        read_lines_into_string(self)
+    }
+
+}
+
+impl ReadLinesIntoStringOnRefSelf for str {
+
+    /// Read lines into String; keep each line end `\n` or `\r\n`.
+    /// 
+    /// ```
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let string: String = "example.txt".read_lines_into_string().unwrap();
+    /// ```
+    /// 
+    fn read_lines_into_string(&self) -> crate::Result<String> {
+        read_lines_into_string(self)
+    }
+
+}
+
+impl ReadLinesIntoStringOnRefSelf for String {
+
+    fn read_lines_into_string(&self) -> crate::Result<String> {
+        read_lines_into_string(self)
+    }
+
+}
+
+impl ReadLinesIntoStringOnRefSelf for OsStr {
+
+    fn read_lines_into_string(&self) -> crate::Result<String> {
+        read_lines_into_string(self)
     }
 
 }
@@ -55,4 +106,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn with_path_buf() {
+        let path_buf = std::path::PathBuf::from("example.txt");
+        assert_eq!(
+            read_lines_into_string(&path_buf).unwrap(),
+            "lorem\nipsum\n"
+        );
+        assert_eq!(
+            path_buf.read_lines_into_string().unwrap(),
+            "lorem\nipsum\n"
+        );
+    }
+
+    #[test]
+    fn with_str_and_string_and_os_str() {
+        assert_eq!(
+            "example.txt".read_lines_into_string().unwrap(),
+            "lorem\nipsum\n"
+        );
+        assert_eq!(
+            String::from("example.txt").read_lines_into_string().unwrap(),
+            "lorem\nipsum\n"
+        );
+        assert_eq!(
+            OsStr::new("example.txt").read_lines_into_string().unwrap(),
+            "lorem\nipsum\n"
+        );
+    }
+
 }
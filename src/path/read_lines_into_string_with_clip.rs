@@ -1,7 +1,27 @@
+use std::ffi::OsStr;
 use std::path::Path;
 use std::fs::File;
+use crate::error::Error;
 use crate::traits::*;
 
+/// Read lines into String; clip each line end `\n` or `\r\n`.
+/// 
+/// ```
+/// use std::path::PathBuf;
+/// use read_lines_into::path::read_lines_into_string_with_clip;
+/// 
+/// let string: String = read_lines_into_string_with_clip("example.txt").unwrap();
+/// let string: String = read_lines_into_string_with_clip(PathBuf::from("example.txt")).unwrap();
+/// ```
+/// 
+/// Any error will return immediately.
+/// 
+pub fn read_lines_into_string_with_clip(path: impl AsRef<Path>) -> crate::Result<String> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    file.read_lines_into_string_with_clip().map_err(|e| e.with_path(path))
+}
+
 impl ReadLinesIntoStringWithClipOnRefSelf for Path {
 
     /// Read lines into String; clip each line end `\n` or `\r\n`.
@@ -17,9 +37,40 @@ impl ReadLinesIntoStringWithClipOnRefSelf for Path {
     /// 
     /// Any error will return immediately.
     /// 
-    fn read_lines_into_string_with_clip(&self) -> ::std::io::Result<String> {
-        let file = File::open(&self)?;
-        file.read_lines_into_string_with_clip()
+    fn read_lines_into_string_with_clip(&self) -> crate::Result<String> {
+        read_lines_into_string_with_clip(self)
+    }
+
+}
+
+impl ReadLinesIntoStringWithClipOnRefSelf for str {
+
+    /// Read lines into String; clip each line end `\n` or `\r\n`.
+    /// 
+    /// ```
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let string: String = "example.txt".read_lines_into_string_with_clip().unwrap();
+    /// ```
+    /// 
+    fn read_lines_into_string_with_clip(&self) -> crate::Result<String> {
+        read_lines_into_string_with_clip(self)
+    }
+
+}
+
+impl ReadLinesIntoStringWithClipOnRefSelf for String {
+
+    fn read_lines_into_string_with_clip(&self) -> crate::Result<String> {
+        read_lines_into_string_with_clip(self)
+    }
+
+}
+
+impl ReadLinesIntoStringWithClipOnRefSelf for OsStr {
+
+    fn read_lines_into_string_with_clip(&self) -> crate::Result<String> {
+        read_lines_into_string_with_clip(self)
     }
 
 }
@@ -56,4 +107,33 @@ use super::*;
         );
     }
 
+    #[test]
+    fn with_path_buf() {
+        let path_buf = std::path::PathBuf::from("example.txt");
+        assert_eq!(
+            read_lines_into_string_with_clip(&path_buf).unwrap(),
+            "loremipsum"
+        );
+        assert_eq!(
+            path_buf.read_lines_into_string_with_clip().unwrap(),
+            "loremipsum"
+        );
+    }
+
+    #[test]
+    fn with_str_and_string_and_os_str() {
+        assert_eq!(
+            "example.txt".read_lines_into_string_with_clip().unwrap(),
+            "loremipsum"
+        );
+        assert_eq!(
+            String::from("example.txt").read_lines_into_string_with_clip().unwrap(),
+            "loremipsum"
+        );
+        assert_eq!(
+            OsStr::new("example.txt").read_lines_into_string_with_clip().unwrap(),
+            "loremipsum"
+        );
+    }
+
 }
@@ -0,0 +1,114 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use std::fs::File;
+use crate::error::Error;
+use crate::traits::*;
+
+/// Read lines into String; remove the common leading indentation from all lines, and keep each line end `\n` or `\r\n`.
+/// 
+/// The common indentation is the longest run of spaces and tabs
+/// that starts every line that isn't blank, like Python's `textwrap.dedent`.
+/// Nested indentation is kept.
+/// 
+/// ```
+/// use read_lines_into::path::read_lines_into_string_with_dedent;
+/// 
+/// let string: String = read_lines_into_string_with_dedent("example-with-indent.txt").unwrap();
+/// ```
+/// 
+/// Any error will return immediately.
+/// 
+pub fn read_lines_into_string_with_dedent(path: impl AsRef<Path>) -> crate::Result<String> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    file.read_lines_into_string_with_dedent().map_err(|e| e.with_path(path))
+}
+
+impl ReadLinesIntoStringWithDedentOnRefSelf for Path {
+
+    /// Read lines into String; remove the common leading indentation from all lines, and keep each line end `\n` or `\r\n`.
+    /// 
+    /// The common indentation is the longest run of spaces and tabs
+    /// that starts every line that isn't blank, like Python's `textwrap.dedent`.
+    /// Nested indentation is kept.
+    /// 
+    /// ```
+    /// use std::path::Path;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let path = Path::new("example-with-indent.txt");
+    /// let string: String = path.read_lines_into_string_with_dedent().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_string_with_dedent(&self) -> crate::Result<String> {
+        read_lines_into_string_with_dedent(self)
+    }
+
+}
+
+impl ReadLinesIntoStringWithDedentOnRefSelf for str {
+
+    fn read_lines_into_string_with_dedent(&self) -> crate::Result<String> {
+        read_lines_into_string_with_dedent(self)
+    }
+
+}
+
+impl ReadLinesIntoStringWithDedentOnRefSelf for String {
+
+    fn read_lines_into_string_with_dedent(&self) -> crate::Result<String> {
+        read_lines_into_string_with_dedent(self)
+    }
+
+}
+
+impl ReadLinesIntoStringWithDedentOnRefSelf for OsStr {
+
+    fn read_lines_into_string_with_dedent(&self) -> crate::Result<String> {
+        read_lines_into_string_with_dedent(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_lf() {
+        let path = Path::new("example.txt");
+        assert_eq!(
+            path.read_lines_into_string_with_dedent().unwrap(),
+            "lorem\nipsum\n"
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let path = Path::new("example-with-crlf.txt");
+        assert_eq!(
+            path.read_lines_into_string_with_dedent().unwrap(),
+            "lorem\r\nipsum\r\n"
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let path = Path::new("example-with-indent.txt");
+        assert_eq!(
+            path.read_lines_into_string_with_dedent().unwrap(),
+            "lorem\nipsum\n"
+        );
+    }
+
+    #[test]
+    fn with_str() {
+        assert_eq!(
+            "example.txt".read_lines_into_string_with_dedent().unwrap(),
+            "lorem\nipsum\n"
+        );
+    }
+
+}
@@ -0,0 +1,115 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use std::fs::File;
+use crate::line_ending::NewlineStyle;
+use crate::error::Error;
+use crate::traits::*;
+
+/// Read lines into String; replace each line end `\n` or `\r\n` with the given newline style, LF or CRLF.
+/// 
+/// A last line without a line end stays without a line end,
+/// so the result ends with a newline if and only if the source does.
+/// 
+/// ```
+/// use read_lines_into::NewlineStyle;
+/// use read_lines_into::path::read_lines_into_string_with_normalized_endings;
+/// 
+/// let string: String = read_lines_into_string_with_normalized_endings("example-with-crlf.txt", NewlineStyle::Lf).unwrap();
+/// ```
+/// 
+/// Any error will return immediately.
+/// 
+pub fn read_lines_into_string_with_normalized_endings(path: impl AsRef<Path>, style: NewlineStyle) -> crate::Result<String> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    file.read_lines_into_string_with_normalized_endings(style).map_err(|e| e.with_path(path))
+}
+
+impl ReadLinesIntoStringWithNormalizedEndingsOnRefSelf for Path {
+
+    /// Read lines into String; replace each line end `\n` or `\r\n` with the given newline style, LF or CRLF.
+    /// 
+    /// A last line without a line end stays without a line end,
+    /// so the result ends with a newline if and only if the source does.
+    /// 
+    /// ```
+    /// use std::path::Path;
+    /// use read_lines_into::NewlineStyle;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let path = Path::new("example-with-crlf.txt");
+    /// let string: String = path.read_lines_into_string_with_normalized_endings(NewlineStyle::Lf).unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_string_with_normalized_endings(&self, style: NewlineStyle) -> crate::Result<String> {
+        read_lines_into_string_with_normalized_endings(self, style)
+    }
+
+}
+
+impl ReadLinesIntoStringWithNormalizedEndingsOnRefSelf for str {
+
+    fn read_lines_into_string_with_normalized_endings(&self, style: NewlineStyle) -> crate::Result<String> {
+        read_lines_into_string_with_normalized_endings(self, style)
+    }
+
+}
+
+impl ReadLinesIntoStringWithNormalizedEndingsOnRefSelf for String {
+
+    fn read_lines_into_string_with_normalized_endings(&self, style: NewlineStyle) -> crate::Result<String> {
+        read_lines_into_string_with_normalized_endings(self, style)
+    }
+
+}
+
+impl ReadLinesIntoStringWithNormalizedEndingsOnRefSelf for OsStr {
+
+    fn read_lines_into_string_with_normalized_endings(&self, style: NewlineStyle) -> crate::Result<String> {
+        read_lines_into_string_with_normalized_endings(self, style)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_lf() {
+        let path = Path::new("example.txt");
+        assert_eq!(
+            path.read_lines_into_string_with_normalized_endings(NewlineStyle::CrLf).unwrap(),
+            "lorem\r\nipsum\r\n"
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let path = Path::new("example-with-crlf.txt");
+        assert_eq!(
+            path.read_lines_into_string_with_normalized_endings(NewlineStyle::Lf).unwrap(),
+            "lorem\nipsum\n"
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let path = Path::new("example-with-indent.txt");
+        assert_eq!(
+            path.read_lines_into_string_with_normalized_endings(NewlineStyle::CrLf).unwrap(),
+            "    lorem\r\n    ipsum\r\n"
+        );
+    }
+
+    #[test]
+    fn with_str() {
+        assert_eq!(
+            "example-with-crlf.txt".read_lines_into_string_with_normalized_endings(NewlineStyle::Lf).unwrap(),
+            "example.txt".read_lines_into_string().unwrap()
+        );
+    }
+
+}
@@ -1,7 +1,27 @@
+use std::ffi::OsStr;
 use std::path::Path;
 use std::fs::File;
+use crate::error::Error;
 use crate::traits::*;
 
+/// Read lines into String; trim each line of whitespace.
+/// 
+/// ```
+/// use std::path::PathBuf;
+/// use read_lines_into::path::read_lines_into_string_with_trim;
+/// 
+/// let string: String = read_lines_into_string_with_trim("example.txt").unwrap();
+/// let string: String = read_lines_into_string_with_trim(PathBuf::from("example.txt")).unwrap();
+/// ```
+/// 
+/// Any error will return immediately.
+/// 
+pub fn read_lines_into_string_with_trim(path: impl AsRef<Path>) -> crate::Result<String> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    file.read_lines_into_string_with_trim().map_err(|e| e.with_path(path))
+}
+
 impl ReadLinesIntoStringWithTrimOnRefSelf for Path {
 
     /// Read lines into String; trim each line of whitespace.
@@ -17,9 +37,40 @@ impl ReadLinesIntoStringWithTrimOnRefSelf for Path {
     /// 
     /// Any error will return immediately.
     /// 
-    fn read_lines_into_string_with_trim(&self) -> ::std::io::Result<String> {
-        let file = File::open(&self)?;
-        file.read_lines_into_string_with_trim()
+    fn read_lines_into_string_with_trim(&self) -> crate::Result<String> {
+        read_lines_into_string_with_trim(self)
+    }
+
+}
+
+impl ReadLinesIntoStringWithTrimOnRefSelf for str {
+
+    /// Read lines into String; trim each line of whitespace.
+    /// 
+    /// ```
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let string: String = "example.txt".read_lines_into_string_with_trim().unwrap();
+    /// ```
+    /// 
+    fn read_lines_into_string_with_trim(&self) -> crate::Result<String> {
+        read_lines_into_string_with_trim(self)
+    }
+
+}
+
+impl ReadLinesIntoStringWithTrimOnRefSelf for String {
+
+    fn read_lines_into_string_with_trim(&self) -> crate::Result<String> {
+        read_lines_into_string_with_trim(self)
+    }
+
+}
+
+impl ReadLinesIntoStringWithTrimOnRefSelf for OsStr {
+
+    fn read_lines_into_string_with_trim(&self) -> crate::Result<String> {
+        read_lines_into_string_with_trim(self)
     }
 
 }
@@ -56,4 +107,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn with_path_buf() {
+        let path_buf = std::path::PathBuf::from("example.txt");
+        assert_eq!(
+            read_lines_into_string_with_trim(&path_buf).unwrap(),
+            "loremipsum"
+        );
+        assert_eq!(
+            path_buf.read_lines_into_string_with_trim().unwrap(),
+            "loremipsum"
+        );
+    }
+
+    #[test]
+    fn with_str_and_string_and_os_str() {
+        assert_eq!(
+            "example.txt".read_lines_into_string_with_trim().unwrap(),
+            "loremipsum"
+        );
+        assert_eq!(
+            String::from("example.txt").read_lines_into_string_with_trim().unwrap(),
+            "loremipsum"
+        );
+        assert_eq!(
+            OsStr::new("example.txt").read_lines_into_string_with_trim().unwrap(),
+            "loremipsum"
+        );
+    }
+
 }
@@ -0,0 +1,106 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use std::fs::File;
+use crate::error::Error;
+use crate::traits::*;
+
+/// Read lines into Vec<Vec<u8>>, without UTF-8 validation; keep each line end `\n` or `\r\n`.
+/// 
+/// ```
+/// use read_lines_into::path::read_lines_into_vec_bytes;
+/// 
+/// let lines: Vec<Vec<u8>> = read_lines_into_vec_bytes("example.txt").unwrap();
+/// ```
+/// 
+/// Any error will return immediately.
+/// 
+pub fn read_lines_into_vec_bytes(path: impl AsRef<Path>) -> crate::Result<Vec<Vec<u8>>> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    file.read_lines_into_vec_bytes().map_err(|e| e.with_path(path))
+}
+
+impl ReadLinesIntoVecBytesOnRefSelf for Path {
+
+    /// Read lines into Vec<Vec<u8>>, without UTF-8 validation; keep each line end `\n` or `\r\n`.
+    /// 
+    /// ```
+    /// use std::path::Path;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let path = Path::new("example.txt");
+    /// let lines: Vec<Vec<u8>> = path.read_lines_into_vec_bytes().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_vec_bytes(&self) -> crate::Result<Vec<Vec<u8>>> {
+        read_lines_into_vec_bytes(self)
+    }
+
+}
+
+impl ReadLinesIntoVecBytesOnRefSelf for str {
+
+    fn read_lines_into_vec_bytes(&self) -> crate::Result<Vec<Vec<u8>>> {
+        read_lines_into_vec_bytes(self)
+    }
+
+}
+
+impl ReadLinesIntoVecBytesOnRefSelf for String {
+
+    fn read_lines_into_vec_bytes(&self) -> crate::Result<Vec<Vec<u8>>> {
+        read_lines_into_vec_bytes(self)
+    }
+
+}
+
+impl ReadLinesIntoVecBytesOnRefSelf for OsStr {
+
+    fn read_lines_into_vec_bytes(&self) -> crate::Result<Vec<Vec<u8>>> {
+        read_lines_into_vec_bytes(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_lf() {
+        let path = Path::new("example.txt");
+        assert_eq!(
+            path.read_lines_into_vec_bytes().unwrap(),
+            vec![b"lorem\n".to_vec(), b"ipsum\n".to_vec()]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let path = Path::new("example-with-crlf.txt");
+        assert_eq!(
+            path.read_lines_into_vec_bytes().unwrap(),
+            vec![b"lorem\r\n".to_vec(), b"ipsum\r\n".to_vec()]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let path = Path::new("example-with-indent.txt");
+        assert_eq!(
+            path.read_lines_into_vec_bytes().unwrap(),
+            vec![b"    lorem\n".to_vec(), b"    ipsum\n".to_vec()]
+        );
+    }
+
+    #[test]
+    fn with_str() {
+        assert_eq!(
+            "example.txt".read_lines_into_vec_bytes().unwrap(),
+            vec![b"lorem\n".to_vec(), b"ipsum\n".to_vec()]
+        );
+    }
+
+}
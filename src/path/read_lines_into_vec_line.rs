@@ -0,0 +1,122 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use std::fs::File;
+use crate::line::Line;
+use crate::error::Error;
+use crate::traits::*;
+
+/// Read lines into Vec<Line>; each line record has its line number,
+/// byte offset, content, and line ending.
+/// 
+/// ```
+/// use read_lines_into::line::Line;
+/// use read_lines_into::path::read_lines_into_vec_line;
+/// 
+/// let lines: Vec<Line> = read_lines_into_vec_line("example.txt").unwrap();
+/// ```
+/// 
+/// Any error will return immediately.
+/// 
+pub fn read_lines_into_vec_line(path: impl AsRef<Path>) -> crate::Result<Vec<Line>> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    file.read_lines_into_vec_line().map_err(|e| e.with_path(path))
+}
+
+impl ReadLinesIntoLinesOnRefSelf for Path {
+
+    /// Read lines into Vec<Line>; each line record has its line number,
+    /// byte offset, content, and line ending.
+    /// 
+    /// ```
+    /// use std::path::Path;
+    /// use read_lines_into::line::Line;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let path = Path::new("example.txt");
+    /// let lines: Vec<Line> = path.read_lines_into_vec_line().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_vec_line(&self) -> crate::Result<Vec<Line>> {
+        read_lines_into_vec_line(self)
+    }
+
+}
+
+impl ReadLinesIntoLinesOnRefSelf for str {
+
+    fn read_lines_into_vec_line(&self) -> crate::Result<Vec<Line>> {
+        read_lines_into_vec_line(self)
+    }
+
+}
+
+impl ReadLinesIntoLinesOnRefSelf for String {
+
+    fn read_lines_into_vec_line(&self) -> crate::Result<Vec<Line>> {
+        read_lines_into_vec_line(self)
+    }
+
+}
+
+impl ReadLinesIntoLinesOnRefSelf for OsStr {
+
+    fn read_lines_into_vec_line(&self) -> crate::Result<Vec<Line>> {
+        read_lines_into_vec_line(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn clipped(lines: Vec<Line>) -> Vec<String> {
+        lines.iter().map(|x| String::from(x.clipped())).collect()
+    }
+
+    fn trimmed(lines: Vec<Line>) -> Vec<String> {
+        lines.iter().map(|x| String::from(x.trimmed())).collect()
+    }
+
+    #[test]
+    fn with_lf() {
+        let path = Path::new("example.txt");
+        assert_eq!(
+            clipped(path.read_lines_into_vec_line().unwrap()),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let path = Path::new("example-with-crlf.txt");
+        assert_eq!(
+            clipped(path.read_lines_into_vec_line().unwrap()),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let path = Path::new("example-with-indent.txt");
+        assert_eq!(
+            clipped(path.read_lines_into_vec_line().unwrap()),
+            vec![String::from("    lorem"), String::from("    ipsum")]
+        );
+        assert_eq!(
+            trimmed(path.read_lines_into_vec_line().unwrap()),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_str() {
+        let lines = "example-with-indent.txt".read_lines_into_vec_line().unwrap();
+        assert_eq!(lines[1].number, 2);
+        assert_eq!(lines[1].byte_offset, 10);
+    }
+
+}
@@ -0,0 +1,134 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use std::fs::File;
+use std::str::FromStr;
+use crate::error::Error;
+use crate::parse_lines_error::ParseLinesError;
+use crate::traits::*;
+
+/// Read lines into Vec<T>; clip each line end `\n` or `\r\n`, trim each line of whitespace, then parse each line using `FromStr`.
+/// 
+/// To skip blank lines and comment lines, use `ReadLinesOptions::read_parsed`.
+/// 
+/// ```
+/// use read_lines_into::path::read_lines_into_vec_parsed;
+/// 
+/// let strings: Vec<String> = read_lines_into_vec_parsed("example.txt").unwrap();
+/// ```
+/// 
+/// Any error will return immediately. A parse error has the path,
+/// the line number, the line, and the `FromStr` error.
+/// 
+pub fn read_lines_into_vec_parsed<T: FromStr>(path: impl AsRef<Path>) -> Result<Vec<T>, ParseLinesError<T::Err>> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    file.read_lines_into_vec_parsed().map_err(|e| e.with_path(path))
+}
+
+impl ReadLinesIntoParsedOnRefSelf for Path {
+
+    /// Read lines into Vec<T>; clip each line end `\n` or `\r\n`, trim each line of whitespace, then parse each line using `FromStr`.
+    /// 
+    /// To skip blank lines and comment lines, use `ReadLinesOptions::read_parsed`.
+    /// 
+    /// ```
+    /// use std::path::Path;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let path = Path::new("example.txt");
+    /// let strings: Vec<String> = path.read_lines_into_vec_parsed().unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately. A parse error has the path,
+    /// the line number, the line, and the `FromStr` error.
+    /// 
+    fn read_lines_into_vec_parsed<T: FromStr>(&self) -> Result<Vec<T>, ParseLinesError<T::Err>> {
+        read_lines_into_vec_parsed(self)
+    }
+
+}
+
+impl ReadLinesIntoParsedOnRefSelf for str {
+
+    fn read_lines_into_vec_parsed<T: FromStr>(&self) -> Result<Vec<T>, ParseLinesError<T::Err>> {
+        read_lines_into_vec_parsed(self)
+    }
+
+}
+
+impl ReadLinesIntoParsedOnRefSelf for String {
+
+    fn read_lines_into_vec_parsed<T: FromStr>(&self) -> Result<Vec<T>, ParseLinesError<T::Err>> {
+        read_lines_into_vec_parsed(self)
+    }
+
+}
+
+impl ReadLinesIntoParsedOnRefSelf for OsStr {
+
+    fn read_lines_into_vec_parsed<T: FromStr>(&self) -> Result<Vec<T>, ParseLinesError<T::Err>> {
+        read_lines_into_vec_parsed(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_lf() {
+        let path = Path::new("example.txt");
+        assert_eq!(
+            path.read_lines_into_vec_parsed::<String>().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let path = Path::new("example-with-crlf.txt");
+        assert_eq!(
+            path.read_lines_into_vec_parsed::<String>().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let path = Path::new("example-with-indent.txt");
+        assert_eq!(
+            path.read_lines_into_vec_parsed::<String>().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_str_and_string_and_os_str() {
+        assert_eq!(
+            "example.txt".read_lines_into_vec_parsed::<String>().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+        assert_eq!(
+            String::from("example.txt").read_lines_into_vec_parsed::<String>().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+        assert_eq!(
+            OsStr::new("example.txt").read_lines_into_vec_parsed::<String>().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_parse_error() {
+        match Path::new("example-with-crlf.txt").read_lines_into_vec_parsed::<u64>().unwrap_err() {
+            ParseLinesError::Parse { path, line, content, .. } => {
+                assert_eq!(path.unwrap(), Path::new("example-with-crlf.txt"));
+                assert_eq!(line, 1);
+                assert_eq!(content, "lorem");
+            }
+            ParseLinesError::Read(e) => panic!("{}", e),
+        }
+    }
+
+}
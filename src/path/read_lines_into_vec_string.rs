@@ -1,7 +1,27 @@
+use std::ffi::OsStr;
 use std::path::Path;
 use std::fs::File;
+use crate::error::Error;
 use crate::traits::*;
 
+/// Read lines into Vec<String>; keep each line end `\n` or `\r\n`.
+/// 
+/// ```
+/// use std::path::PathBuf;
+/// use read_lines_into::path::read_lines_into_vec_string;
+/// 
+/// let strings: Vec<String> = read_lines_into_vec_string("example.txt").unwrap();
+/// let strings: Vec<String> = read_lines_into_vec_string(PathBuf::from("example.txt")).unwrap();
+/// ```
+/// 
+/// Any error will return immediately.
+/// 
+pub fn read_lines_into_vec_string(path: impl AsRef<Path>) -> crate::Result<Vec<String>> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    file.read_lines_into_vec_string().map_err(|e| e.with_path(path))
+}
+
 impl ReadLinesIntoStringsOnRefSelf for Path {
 
     /// Read lines into Vec<String>; keep each line end `\n` or `\r\n`.
@@ -17,9 +37,40 @@ impl ReadLinesIntoStringsOnRefSelf for Path {
     /// 
     /// Any error will return immediately.
     /// 
-    fn read_lines_into_vec_string(&self) -> ::std::io::Result<Vec<String>> {
-        let file = File::open(&self)?;
-        file.read_lines_into_vec_string()
+    fn read_lines_into_vec_string(&self) -> crate::Result<Vec<String>> {
+        read_lines_into_vec_string(self)
+    }
+
+}
+
+impl ReadLinesIntoStringsOnRefSelf for str {
+
+    /// Read lines into Vec<String>; keep each line end `\n` or `\r\n`.
+    /// 
+    /// ```
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let strings: Vec<String> = "example.txt".read_lines_into_vec_string().unwrap();
+    /// ```
+    /// 
+    fn read_lines_into_vec_string(&self) -> crate::Result<Vec<String>> {
+        read_lines_into_vec_string(self)
+    }
+
+}
+
+impl ReadLinesIntoStringsOnRefSelf for String {
+
+    fn read_lines_into_vec_string(&self) -> crate::Result<Vec<String>> {
+        read_lines_into_vec_string(self)
+    }
+
+}
+
+impl ReadLinesIntoStringsOnRefSelf for OsStr {
+
+    fn read_lines_into_vec_string(&self) -> crate::Result<Vec<String>> {
+        read_lines_into_vec_string(self)
     }
 
 }
@@ -55,4 +106,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn with_path_buf() {
+        let path_buf = std::path::PathBuf::from("example.txt");
+        assert_eq!(
+            read_lines_into_vec_string(&path_buf).unwrap(),
+            vec![String::from("lorem\n"), String::from("ipsum\n")]
+        );
+        assert_eq!(
+            path_buf.read_lines_into_vec_string().unwrap(),
+            vec![String::from("lorem\n"), String::from("ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_str_and_string_and_os_str() {
+        assert_eq!(
+            "example.txt".read_lines_into_vec_string().unwrap(),
+            vec![String::from("lorem\n"), String::from("ipsum\n")]
+        );
+        assert_eq!(
+            String::from("example.txt").read_lines_into_vec_string().unwrap(),
+            vec![String::from("lorem\n"), String::from("ipsum\n")]
+        );
+        assert_eq!(
+            OsStr::new("example.txt").read_lines_into_vec_string().unwrap(),
+            vec![String::from("lorem\n"), String::from("ipsum\n")]
+        );
+    }
+
+    #[test]
+    fn with_open_error() {
+        match read_lines_into_vec_string("missing.txt").unwrap_err() {
+            Error::Open { path, source } => {
+                assert_eq!(path, Path::new("missing.txt"));
+                assert_eq!(source.kind(), ::std::io::ErrorKind::NotFound);
+            }
+            e => panic!("{:?}", e),
+        }
+    }
+
+    #[test]
+    fn with_invalid_utf8() {
+        assert_eq!(
+            read_lines_into_vec_string("example-with-latin1.txt").unwrap_err().to_string(),
+            "example-with-latin1.txt:2: stream did not contain valid UTF-8 at byte offset 15"
+        );
+    }
+
 }
@@ -1,7 +1,27 @@
+use std::ffi::OsStr;
 use std::path::Path;
 use std::fs::File;
+use crate::error::Error;
 use crate::traits::*;
 
+/// Read lines into Vec<String>; clip each line end `\n` or `\r\n`.
+/// 
+/// ```
+/// use std::path::PathBuf;
+/// use read_lines_into::path::read_lines_into_vec_string_with_clip;
+/// 
+/// let strings: Vec<String> = read_lines_into_vec_string_with_clip("example.txt").unwrap();
+/// let strings: Vec<String> = read_lines_into_vec_string_with_clip(PathBuf::from("example.txt")).unwrap();
+/// ```
+/// 
+/// Any error will return immediately.
+/// 
+pub fn read_lines_into_vec_string_with_clip(path: impl AsRef<Path>) -> crate::Result<Vec<String>> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    file.read_lines_into_vec_string_with_clip().map_err(|e| e.with_path(path))
+}
+
 impl ReadLinesIntoStringsWithClipOnRefSelf for Path {
 
     /// Read lines into Vec<String>; clip each line end `\n` or `\r\n`.
@@ -17,9 +37,40 @@ impl ReadLinesIntoStringsWithClipOnRefSelf for Path {
     /// 
     /// Any error will return immediately.
     /// 
-    fn read_lines_into_vec_string_with_clip(&self) -> ::std::io::Result<Vec<String>> {
-        let file = File::open(&self)?;
-        file.read_lines_into_vec_string_with_clip()
+    fn read_lines_into_vec_string_with_clip(&self) -> crate::Result<Vec<String>> {
+        read_lines_into_vec_string_with_clip(self)
+    }
+
+}
+
+impl ReadLinesIntoStringsWithClipOnRefSelf for str {
+
+    /// Read lines into Vec<String>; clip each line end `\n` or `\r\n`.
+    /// 
+    /// ```
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let strings: Vec<String> = "example.txt".read_lines_into_vec_string_with_clip().unwrap();
+    /// ```
+    /// 
+    fn read_lines_into_vec_string_with_clip(&self) -> crate::Result<Vec<String>> {
+        read_lines_into_vec_string_with_clip(self)
+    }
+
+}
+
+impl ReadLinesIntoStringsWithClipOnRefSelf for String {
+
+    fn read_lines_into_vec_string_with_clip(&self) -> crate::Result<Vec<String>> {
+        read_lines_into_vec_string_with_clip(self)
+    }
+
+}
+
+impl ReadLinesIntoStringsWithClipOnRefSelf for OsStr {
+
+    fn read_lines_into_vec_string_with_clip(&self) -> crate::Result<Vec<String>> {
+        read_lines_into_vec_string_with_clip(self)
     }
 
 }
@@ -55,4 +106,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn with_path_buf() {
+        let path_buf = std::path::PathBuf::from("example.txt");
+        assert_eq!(
+            read_lines_into_vec_string_with_clip(&path_buf).unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+        assert_eq!(
+            path_buf.read_lines_into_vec_string_with_clip().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_str_and_string_and_os_str() {
+        assert_eq!(
+            "example.txt".read_lines_into_vec_string_with_clip().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+        assert_eq!(
+            String::from("example.txt").read_lines_into_vec_string_with_clip().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+        assert_eq!(
+            OsStr::new("example.txt").read_lines_into_vec_string_with_clip().unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
 }
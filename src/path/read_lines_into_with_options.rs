@@ -0,0 +1,123 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use std::fs::File;
+use crate::options::*;
+use crate::error::Error;
+use crate::traits::*;
+
+/// Read lines into any collection of strings, such as String or Vec<String>, with options.
+/// 
+/// ```
+/// use read_lines_into::options::*;
+/// use read_lines_into::path::read_lines_into_with_options;
+/// 
+/// let options = ReadLinesOptions::new().endings(Endings::Clip);
+/// let strings: Vec<String> = read_lines_into_with_options("example.txt", &options).unwrap();
+/// ```
+/// 
+/// Any error will return immediately.
+/// 
+pub fn read_lines_into_with_options<C: FromIterator<String>>(path: impl AsRef<Path>, options: &ReadLinesOptions) -> crate::Result<C> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    file.read_lines_into_with_options(options).map_err(|e| e.with_path(path))
+}
+
+impl ReadLinesIntoWithOptionsOnRefSelf for Path {
+
+    /// Read lines into any collection of strings, such as String or Vec<String>, with options.
+    /// 
+    /// ```
+    /// use std::path::Path;
+    /// use read_lines_into::options::*;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let path = Path::new("example.txt");
+    /// let options = ReadLinesOptions::new().endings(Endings::Clip);
+    /// let strings: Vec<String> = path.read_lines_into_with_options(&options).unwrap();
+    /// ```
+    /// 
+    /// Any error will return immediately.
+    /// 
+    fn read_lines_into_with_options<C: FromIterator<String>>(&self, options: &ReadLinesOptions) -> crate::Result<C> {
+        read_lines_into_with_options(self, options)
+    }
+
+}
+
+impl ReadLinesIntoWithOptionsOnRefSelf for str {
+
+    fn read_lines_into_with_options<C: FromIterator<String>>(&self, options: &ReadLinesOptions) -> crate::Result<C> {
+        read_lines_into_with_options(self, options)
+    }
+
+}
+
+impl ReadLinesIntoWithOptionsOnRefSelf for String {
+
+    fn read_lines_into_with_options<C: FromIterator<String>>(&self, options: &ReadLinesOptions) -> crate::Result<C> {
+        read_lines_into_with_options(self, options)
+    }
+
+}
+
+impl ReadLinesIntoWithOptionsOnRefSelf for OsStr {
+
+    fn read_lines_into_with_options<C: FromIterator<String>>(&self, options: &ReadLinesOptions) -> crate::Result<C> {
+        read_lines_into_with_options(self, options)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options() -> ReadLinesOptions {
+        ReadLinesOptions::new().endings(Endings::Clip).whitespace(Whitespace::TrimEnd)
+    }
+
+    #[test]
+    fn with_lf() {
+        let path = Path::new("example.txt");
+        assert_eq!(
+            path.read_lines_into_with_options::<Vec<String>>(&options()).unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let path = Path::new("example-with-crlf.txt");
+        assert_eq!(
+            path.read_lines_into_with_options::<Vec<String>>(&options()).unwrap(),
+            vec![String::from("lorem"), String::from("ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let path = Path::new("example-with-indent.txt");
+        assert_eq!(
+            path.read_lines_into_with_options::<Vec<String>>(&options()).unwrap(),
+            vec![String::from("    lorem"), String::from("    ipsum")]
+        );
+    }
+
+    #[test]
+    fn with_str_and_string_and_os_str() {
+        assert_eq!(
+            "example.txt".read_lines_into_with_options::<String>(&options()).unwrap(),
+            "loremipsum"
+        );
+        assert_eq!(
+            String::from("example.txt").read_lines_into_with_options::<String>(&options()).unwrap(),
+            "loremipsum"
+        );
+        assert_eq!(
+            OsStr::new("example.txt").read_lines_into_with_options::<String>(&options()).unwrap(),
+            "loremipsum"
+        );
+    }
+
+}
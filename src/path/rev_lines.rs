@@ -0,0 +1,134 @@
+use std::ffi::OsStr;
+use std::path::Path;
+use std::fs::File;
+use std::io::BufReader;
+use crate::iter::RevLines;
+use crate::error::Error;
+use crate::traits::*;
+
+/// Iterate lines in reverse, from last to first, one String at a time; keep each line end `\n` or `\r\n`.
+/// 
+/// ```
+/// use read_lines_into::path::rev_lines;
+/// 
+/// for line in rev_lines("example.txt").unwrap() {
+///     let string: String = line.unwrap();
+/// }
+/// ```
+/// 
+/// Any error opening the file will return immediately;
+/// any error reading is yielded as an item.
+/// 
+pub fn rev_lines(path: impl AsRef<Path>) -> crate::Result<RevLines<BufReader<File>>> {
+    let path = path.as_ref();
+    let file = File::open(path).map_err(|e| Error::open(path, e))?;
+    Ok(BufReader::new(file).rev_lines().path(path))
+}
+
+impl RevLinesOnRefSelf for Path {
+
+    /// Iterate lines in reverse, from last to first, one String at a time; keep each line end `\n` or `\r\n`.
+    /// 
+    /// ```
+    /// use std::path::Path;
+    /// use read_lines_into::traits::*;
+    /// 
+    /// let path = Path::new("example.txt");
+    /// for line in path.rev_lines().unwrap() {
+    ///     let string: String = line.unwrap();
+    /// }
+    /// ```
+    /// 
+    /// Any error opening the file will return immediately;
+    /// any error reading is yielded as an item.
+    /// 
+    fn rev_lines(&self) -> crate::Result<RevLines<BufReader<File>>> {
+        rev_lines(self)
+    }
+
+}
+
+impl RevLinesOnRefSelf for str {
+
+    fn rev_lines(&self) -> crate::Result<RevLines<BufReader<File>>> {
+        rev_lines(self)
+    }
+
+}
+
+impl RevLinesOnRefSelf for String {
+
+    fn rev_lines(&self) -> crate::Result<RevLines<BufReader<File>>> {
+        rev_lines(self)
+    }
+
+}
+
+impl RevLinesOnRefSelf for OsStr {
+
+    fn rev_lines(&self) -> crate::Result<RevLines<BufReader<File>>> {
+        rev_lines(self)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn with_lf() {
+        let path = Path::new("example.txt");
+        assert_eq!(
+            path.rev_lines().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("ipsum\n"), String::from("lorem\n")]
+        );
+    }
+
+    #[test]
+    fn with_crlf() {
+        let path = Path::new("example-with-crlf.txt");
+        assert_eq!(
+            path.rev_lines().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("ipsum\r\n"), String::from("lorem\r\n")]
+        );
+    }
+
+    #[test]
+    fn with_indent() {
+        let path = Path::new("example-with-indent.txt");
+        assert_eq!(
+            path.rev_lines().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("    ipsum\n"), String::from("    lorem\n")]
+        );
+    }
+
+    #[test]
+    fn with_str() {
+        assert_eq!(
+            "example.txt".rev_lines().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("ipsum\n"), String::from("lorem\n")]
+        );
+    }
+
+    #[test]
+    fn with_invalid_utf8() {
+        let e = Path::new("example-with-latin1.txt").rev_lines().unwrap().next().unwrap().unwrap_err();
+        assert_eq!(e.to_string(), "example-with-latin1.txt: stream did not contain valid UTF-8 at byte offset 15");
+    }
+
+    #[test]
+    fn with_missing_file() {
+        assert!(rev_lines("missing.txt").is_err());
+    }
+
+    #[test]
+    fn without_final_newline() {
+        let path = Path::new("example-without-final-newline.txt");
+        assert_eq!(
+            path.rev_lines().unwrap().collect::<crate::Result<Vec<String>>>().unwrap(),
+            vec![String::from("ipsum"), String::from("lorem\n")]
+        );
+    }
+
+}
@@ -0,0 +1,357 @@
+//! Read lines backwards from the end of a seekable source, such as
+//! for the last lines of a huge log, without reading the whole source.
+//!
+//! This splits lines the same way as `LineReader` reading forwards,
+//! including a CRLF, or a multi-byte line terminator, that is split
+//! across blocks, and a last line without any line ending.
+
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+use crate::error::Error;
+use crate::error::Limit;
+use crate::limits::LimitAction;
+use crate::limits::Limits;
+use crate::limits::truncate_point;
+use crate::line_ending::LineEnding;
+use crate::line_terminators::Classify;
+use crate::line_terminators::LineTerminators;
+use crate::line_terminators::MAX_TERMINATOR_LEN;
+
+/// How many bytes to read at a time, at least.
+const BLOCK_LEN: usize = 8192;
+
+/// Read lines backwards, one block at a time.
+#[derive(Debug)]
+pub(crate) struct ReverseLineReader<R> {
+    reader: R,
+    terminators: LineTerminators,
+    lossy: bool,
+    limits: Limits,
+    block_len: usize,
+    /// The bytes read from the source, that are not yet read as lines.
+    buf: Vec<u8>,
+    /// The byte offset in the source of the start of `buf`.
+    pos: u64,
+    /// The length of the source, once the end of the source is found.
+    len: Option<u64>,
+    /// The number of lines read.
+    lines: usize,
+    /// Set when a limit stops reading.
+    done: bool,
+}
+
+impl<R: Read + Seek> ReverseLineReader<R> {
+
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader,
+            terminators: LineTerminators::DEFAULT,
+            lossy: false,
+            limits: Limits::default(),
+            block_len: BLOCK_LEN,
+            buf: Vec::new(),
+            pos: 0,
+            len: None,
+            lines: 0,
+            done: false,
+        }
+    }
+
+    pub(crate) fn terminators(mut self, terminators: LineTerminators) -> Self {
+        self.terminators = terminators;
+        self
+    }
+
+    /// Set whether to replace invalid UTF-8 with U+FFFD, rather than error.
+    pub(crate) fn lossy(mut self, lossy: bool) -> Self {
+        self.lossy = lossy;
+        self
+    }
+
+    /// Set resource limits. The total bytes are the bytes of the
+    /// lines read, counting from the end of the source.
+    pub(crate) fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    pub(crate) fn into_inner(self) -> R {
+        self.reader
+    }
+
+    /// Read the last `n` lines, in order, as content and line ending.
+    pub(crate) fn read_last(&mut self, n: usize) -> crate::Result<Vec<(String, LineEnding)>> {
+        let mut lines = Vec::new();
+        while lines.len() < n {
+            match self.read_prev() {
+                Some(line) => lines.push(line?),
+                None => break,
+            }
+        }
+        lines.reverse();
+        Ok(lines)
+    }
+
+    /// Read the line before the last line read, as content and line ending.
+    ///
+    /// Return `None` at the start of the source, or when a limit
+    /// with `LimitAction::Truncate` stops reading.
+    pub(crate) fn read_prev(&mut self) -> Option<crate::Result<(String, LineEnding)>> {
+        let (byte_offset, content, ending) = match self.read_prev_bytes() {
+            Ok(line) => line?,
+            Err(e) => return Some(Err(e)),
+        };
+        self.lines += 1;
+        match String::from_utf8(content) {
+            Ok(content) => Some(Ok((content, ending))),
+            Err(e) if self.lossy => Some(Ok((String::from_utf8_lossy(e.as_bytes()).into_owned(), ending))),
+            Err(e) => Some(Err(Error::InvalidUtf8 {
+                path: None,
+                line: None,
+                byte_offset: byte_offset + e.utf8_error().valid_up_to() as u64,
+            })),
+        }
+    }
+
+    /// Read the line before the last line read, as its byte offset,
+    /// its content bytes, and its line ending.
+    fn read_prev_bytes(&mut self) -> crate::Result<Option<(u64, Vec<u8>, LineEnding)>> {
+        if self.done {
+            return Ok(None);
+        }
+        if self.len.is_none() {
+            self.pos = self.reader.seek(SeekFrom::End(0))?;
+            self.len = Some(self.pos);
+        }
+        if let Some((max, action)) = self.limits.max_lines {
+            if self.lines >= max {
+                if self.buf.is_empty() && self.pos == 0 {
+                    return Ok(None);
+                }
+                return self.exceeded(Limit::Lines(max), action);
+            }
+        }
+        // Keep enough bytes before the line ending to know what it is.
+        while self.buf.len() < MAX_TERMINATOR_LEN && self.pos > 0 {
+            if self.read_block()?.is_none() {
+                return self.exceeded_total_bytes();
+            }
+        }
+        if self.buf.is_empty() {
+            return Ok(None);
+        }
+        let ending = self.ending_at(self.buf.len()).unwrap_or(LineEnding::None);
+        let line_end = self.pos + (self.buf.len() - ending.as_str().len()) as u64;
+        let max_line_bytes = self.limits.max_line_bytes;
+        // Find the line ending before this line, reading more blocks
+        // as needed, and checking a line ending at the start of the
+        // buffer only when there are no more bytes before it.
+        // When a truncated line is too long, skip to its start,
+        // keeping only the bytes needed to find a line ending.
+        let mut skipping = false;
+        let mut i = self.buf.len() - ending.as_str().len();
+        let start = loop {
+            let lowest = if self.pos > 0 { MAX_TERMINATOR_LEN } else { 1 };
+            while i >= lowest && self.ending_at(i).is_none() {
+                i -= 1;
+            }
+            if i >= lowest {
+                break i;
+            }
+            if self.pos == 0 {
+                break 0;
+            }
+            if let Some((max, action)) = max_line_bytes {
+                if line_end - (self.pos + i as u64) > max as u64 {
+                    if action == LimitAction::Error {
+                        return self.exceeded(Limit::LineBytes(max), action);
+                    }
+                    skipping = true;
+                }
+            }
+            if skipping {
+                self.buf.truncate(i + 1);
+            }
+            match self.read_block()? {
+                Some(n) => i += n,
+                None => return self.exceeded_total_bytes(),
+            }
+        };
+        let start_pos = self.pos + start as u64;
+        if let (Some((max, _)), Some(len)) = (self.limits.max_total_bytes, self.len) {
+            if len - start_pos > max {
+                return self.exceeded_total_bytes();
+            }
+        }
+        let content_len = (line_end - start_pos) as usize;
+        let content = match max_line_bytes {
+            Some((max, action)) if content_len > max => {
+                if action == LimitAction::Error {
+                    return self.exceeded(Limit::LineBytes(max), action);
+                }
+                // Keep the start of the line, at a UTF-8 character boundary.
+                let mut content = vec![0; max + 1];
+                self.reader.seek(SeekFrom::Start(start_pos))?;
+                self.reader.read_exact(&mut content)?;
+                content.truncate(truncate_point(&content, max));
+                self.buf.truncate(start);
+                content
+            }
+            _ => {
+                let mut content = self.buf.split_off(start);
+                content.truncate(content_len);
+                content
+            }
+        };
+        Ok(Some((start_pos, content, ending)))
+    }
+
+    /// Stop reading, because the next line exceeds a limit.
+    fn exceeded<T>(&mut self, limit: Limit, action: LimitAction) -> crate::Result<Option<T>> {
+        self.done = true;
+        match action {
+            LimitAction::Error => Err(Error::LimitExceeded { path: None, line: None, limit }),
+            LimitAction::Truncate => Ok(None),
+        }
+    }
+
+    /// Stop reading, because the next line exceeds the total bytes limit.
+    fn exceeded_total_bytes<T>(&mut self) -> crate::Result<Option<T>> {
+        let (max, action) = self.limits.max_total_bytes.unwrap_or_default();
+        self.exceeded(Limit::TotalBytes(max), action)
+    }
+
+    /// Find the line ending that ends right before `i` in the buffer, if any.
+    ///
+    /// A CR at the end of the buffer is a line ending, because
+    /// the next byte, if any, is not a LF.
+    fn ending_at(&self, i: usize) -> Option<LineEnding> {
+        let bytes = &self.buf[..i];
+        if !self.terminators.is_last_byte(*bytes.last()?) {
+            return None;
+        }
+        match self.terminators.classify(bytes) {
+            Classify::Ending(ending) => Some(ending),
+            Classify::MaybeCrLf if self.buf.get(i) != Some(&b'\n') => Some(LineEnding::Cr),
+            _ => None,
+        }
+    }
+
+    /// Read the block before the buffer, and put it at the start of the buffer.
+    ///
+    /// The block is at least as long as the buffer, so a long line
+    /// takes a few reads, rather than one read per block.
+    ///
+    /// Return how many bytes were read, or `None` if the total
+    /// bytes limit stops reading. The limit leaves room for the
+    /// line ending before a line, to find where the line starts.
+    fn read_block(&mut self) -> crate::Result<Option<usize>> {
+        let mut n = self.pos.min(self.block_len.max(self.buf.len()) as u64) as usize;
+        if let (Some((max, _)), Some(len)) = (self.limits.max_total_bytes, self.len) {
+            let room = max.saturating_add(MAX_TERMINATOR_LEN as u64).saturating_sub(len - self.pos);
+            if room == 0 {
+                return Ok(None);
+            }
+            n = n.min(usize::try_from(room).unwrap_or(usize::MAX));
+        }
+        self.pos -= n as u64;
+        self.reader.seek(SeekFrom::Start(self.pos))?;
+        let mut block = vec![0; n + self.buf.len()];
+        self.reader.read_exact(&mut block[..n])?;
+        block[n..].copy_from_slice(&self.buf);
+        self.buf = block;
+        Ok(Some(n))
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+    use crate::line_reader::LineReader;
+
+    /// Read all lines forwards, for comparison.
+    fn forwards(text: &str, terminators: LineTerminators) -> Vec<(String, LineEnding)> {
+        let mut reader = LineReader::new(Cursor::new(text)).terminators(terminators);
+        let mut lines = Vec::new();
+        while let Some(line) = reader.read_next() {
+            let line = line.unwrap();
+            lines.push((String::from(line.content.as_ref()), line.ending));
+        }
+        lines
+    }
+
+    /// Read all lines backwards, with a given block length.
+    fn backwards(text: &str, terminators: LineTerminators, block_len: usize) -> Vec<(String, LineEnding)> {
+        let mut reader = ReverseLineReader::new(Cursor::new(text)).terminators(terminators);
+        reader.block_len = block_len;
+        let mut lines = Vec::new();
+        while let Some(line) = reader.read_prev() {
+            lines.push(line.unwrap());
+        }
+        lines.reverse();
+        lines
+    }
+
+    #[test]
+    fn same_as_forwards_for_any_block_len() {
+        let texts = [
+            "",
+            "\n",
+            "lorem",
+            "lorem\nipsum\n",
+            "lorem\r\nipsum\r\n",
+            "lorem\r\n\r\nipsum",
+            "\r\r\n\n\r",
+            "lorem\u{2028}ipsum\u{85}dolor\u{2029}\u{0B}\u{0C}sit",
+            "😀\r\n😀\n",
+        ];
+        let sets = [
+            LineTerminators::DEFAULT,
+            LineTerminators::LF,
+            LineTerminators::CR | LineTerminators::LF,
+            LineTerminators::DEFAULT | LineTerminators::CR,
+            LineTerminators::ALL,
+            LineTerminators::NONE,
+        ];
+        for text in texts {
+            for terminators in sets {
+                let expect = forwards(text, terminators);
+                for block_len in 1..8 {
+                    assert_eq!(backwards(text, terminators, block_len), expect, "{:?} {:?} {}", text, terminators, block_len);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn read_last() {
+        let mut reader = ReverseLineReader::new(Cursor::new("lorem\r\nipsum\r\ndolor"));
+        reader.block_len = 1;
+        assert_eq!(reader.read_last(2).unwrap(), vec![
+            (String::from("ipsum"), LineEnding::CrLf),
+            (String::from("dolor"), LineEnding::None),
+        ]);
+        assert_eq!(reader.read_last(5).unwrap(), vec![(String::from("lorem"), LineEnding::CrLf)]);
+        assert_eq!(reader.read_last(5).unwrap(), vec![]);
+    }
+
+    #[test]
+    fn reads_only_the_end() {
+        let text = format!("{}lorem\nipsum\n", "a\n".repeat(1_000_000));
+        let mut reader = ReverseLineReader::new(Cursor::new(text));
+        assert_eq!(reader.read_last(2).unwrap().len(), 2);
+        assert!(reader.pos > 1_000_000);
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        let mut reader = ReverseLineReader::new(Cursor::new(b"lorem\ncaf\xe9\n"));
+        let e = reader.read_prev().unwrap().unwrap_err();
+        assert!(matches!(e, Error::InvalidUtf8 { line: None, byte_offset: 9, .. }));
+        assert_eq!(e.to_string(), "stream did not contain valid UTF-8 at byte offset 9");
+    }
+
+}
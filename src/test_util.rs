@@ -0,0 +1,18 @@
+//! Helpers for tests that write files.
+
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// A path in the temp directory that is unique to a test.
+pub(crate) fn temp_path(name: &str) -> PathBuf {
+    let path = ::std::env::temp_dir().join(format!("read_lines_into-{}-{}.txt", ::std::process::id(), name));
+    let _ = ::std::fs::remove_file(&path);
+    path
+}
+
+/// Append text to a file, creating the file if needed.
+pub(crate) fn append(path: &Path, bytes: &str) {
+    let mut file = ::std::fs::OpenOptions::new().create(true).append(true).open(path).unwrap();
+    file.write_all(bytes.as_bytes()).unwrap();
+}
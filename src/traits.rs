@@ -1,47 +1,231 @@
 pub trait ReadLinesIntoStringOnSelf {
-    fn read_lines_into_string(self) -> ::std::io::Result<String>;
+    fn read_lines_into_string(self) -> crate::Result<String>;
 }
 
 pub trait ReadLinesIntoStringOnRefSelf {
-    fn read_lines_into_string(&self) -> ::std::io::Result<String>;
+    fn read_lines_into_string(&self) -> crate::Result<String>;
 }
 
 pub trait ReadLinesIntoStringWithClipOnSelf {
-    fn read_lines_into_string_with_clip(self) -> ::std::io::Result<String>;
+    fn read_lines_into_string_with_clip(self) -> crate::Result<String>;
 }
 
 pub trait ReadLinesIntoStringWithClipOnRefSelf {
-    fn read_lines_into_string_with_clip(&self) -> ::std::io::Result<String>;
+    fn read_lines_into_string_with_clip(&self) -> crate::Result<String>;
 }
 
 pub trait ReadLinesIntoStringWithTrimOnSelf {
-    fn read_lines_into_string_with_trim(self) -> ::std::io::Result<String>;
+    fn read_lines_into_string_with_trim(self) -> crate::Result<String>;
 }
 
 pub trait ReadLinesIntoStringWithTrimOnRefSelf {
-    fn read_lines_into_string_with_trim(&self) -> ::std::io::Result<String>;
+    fn read_lines_into_string_with_trim(&self) -> crate::Result<String>;
 }
 
 pub trait ReadLinesIntoStringsOnSelf {
-    fn read_lines_into_vec_string(self) -> ::std::io::Result<Vec<String>>;
+    fn read_lines_into_vec_string(self) -> crate::Result<Vec<String>>;
 }
 
 pub trait ReadLinesIntoStringsOnRefSelf {
-    fn read_lines_into_vec_string(&self) -> ::std::io::Result<Vec<String>>;
+    fn read_lines_into_vec_string(&self) -> crate::Result<Vec<String>>;
 }
 
 pub trait ReadLinesIntoStringsWithClipOnSelf {
-    fn read_lines_into_vec_string_with_clip(self) -> ::std::io::Result<Vec<String>>;
+    fn read_lines_into_vec_string_with_clip(self) -> crate::Result<Vec<String>>;
 }
 
 pub trait ReadLinesIntoStringsWithClipOnRefSelf {
-    fn read_lines_into_vec_string_with_clip(&self) -> ::std::io::Result<Vec<String>>;
+    fn read_lines_into_vec_string_with_clip(&self) -> crate::Result<Vec<String>>;
 }
 
 pub trait ReadLinesIntoStringsWithTrimOnSelf {
-    fn read_lines_into_vec_string_with_trim(self) -> ::std::io::Result<Vec<String>>;
+    fn read_lines_into_vec_string_with_trim(self) -> crate::Result<Vec<String>>;
 }
 
 pub trait ReadLinesIntoStringsWithTrimOnRefSelf {
-    fn read_lines_into_vec_string_with_trim(&self) -> ::std::io::Result<Vec<String>>;
+    fn read_lines_into_vec_string_with_trim(&self) -> crate::Result<Vec<String>>;
+}
+
+pub trait ReadLinesIntoStringTakeOnMutSelf {
+    fn read_lines_into_string_take(&mut self, n: usize) -> crate::Result<String>;
+}
+
+pub trait ReadLinesIntoStringUntilBlankOnMutSelf {
+    fn read_lines_into_string_until_blank(&mut self) -> crate::Result<String>;
+}
+
+pub trait ReadLinesIntoStringsTakeOnMutSelf {
+    fn read_lines_into_vec_string_take(&mut self, n: usize) -> crate::Result<Vec<String>>;
+}
+
+pub trait ReadLinesIntoStringsUntilBlankOnMutSelf {
+    fn read_lines_into_vec_string_until_blank(&mut self) -> crate::Result<Vec<String>>;
+}
+
+pub trait ReadLinesIntoWithOptionsOnSelf {
+    fn read_lines_into_with_options<C: FromIterator<String>>(self, options: &crate::options::ReadLinesOptions) -> crate::Result<C>;
+}
+
+pub trait ReadLinesIntoWithOptionsOnRefSelf {
+    fn read_lines_into_with_options<C: FromIterator<String>>(&self, options: &crate::options::ReadLinesOptions) -> crate::Result<C>;
+}
+
+pub trait LinesWithEndingsOnSelf {
+    fn lines_with_endings(self) -> crate::iter::LinesWithEndings<Self> where Self: Sized;
+}
+
+pub trait LinesWithEndingsOnRefSelf {
+    fn lines_with_endings(&self) -> crate::Result<crate::iter::LinesWithEndings<::std::io::BufReader<::std::fs::File>>>;
+}
+
+pub trait LinesWithClipOnSelf {
+    fn lines_with_clip(self) -> crate::iter::LinesWithClip<Self> where Self: Sized;
+}
+
+pub trait LinesWithClipOnRefSelf {
+    fn lines_with_clip(&self) -> crate::Result<crate::iter::LinesWithClip<::std::io::BufReader<::std::fs::File>>>;
+}
+
+pub trait LinesWithTrimOnSelf {
+    fn lines_with_trim(self) -> crate::iter::LinesWithTrim<Self> where Self: Sized;
+}
+
+pub trait LinesWithTrimOnRefSelf {
+    fn lines_with_trim(&self) -> crate::Result<crate::iter::LinesWithTrim<::std::io::BufReader<::std::fs::File>>>;
+}
+
+pub trait ReadLinesIntoLinesOnSelf {
+    fn read_lines_into_vec_line(self) -> crate::Result<Vec<crate::line::Line>>;
+}
+
+pub trait ReadLinesIntoLinesOnRefSelf {
+    fn read_lines_into_vec_line(&self) -> crate::Result<Vec<crate::line::Line>>;
+}
+
+pub trait ReadLinesIntoStringWithNormalizedEndingsOnSelf {
+    fn read_lines_into_string_with_normalized_endings(self, style: crate::line_ending::NewlineStyle) -> crate::Result<String>;
+}
+
+pub trait ReadLinesIntoStringWithNormalizedEndingsOnRefSelf {
+    fn read_lines_into_string_with_normalized_endings(&self, style: crate::line_ending::NewlineStyle) -> crate::Result<String>;
+}
+
+pub trait ReadLineEndingReportOnSelf {
+    fn read_line_ending_report(self) -> crate::Result<crate::line_ending_report::LineEndingReport>;
+}
+
+pub trait ReadLineEndingReportOnRefSelf {
+    fn read_line_ending_report(&self) -> crate::Result<crate::line_ending_report::LineEndingReport>;
+}
+
+pub trait ReadLinesIntoStringWithDedentOnSelf {
+    fn read_lines_into_string_with_dedent(self) -> crate::Result<String>;
+}
+
+pub trait ReadLinesIntoStringWithDedentOnRefSelf {
+    fn read_lines_into_string_with_dedent(&self) -> crate::Result<String>;
+}
+
+pub trait ReadLinesIntoStringsWithDedentOnSelf {
+    fn read_lines_into_vec_string_with_dedent(self) -> crate::Result<Vec<String>>;
+}
+
+pub trait ReadLinesIntoStringsWithDedentOnRefSelf {
+    fn read_lines_into_vec_string_with_dedent(&self) -> crate::Result<Vec<String>>;
+}
+
+pub trait ReadLinesIntoOnSelf {
+    fn read_lines_into<C: FromIterator<String>>(self) -> crate::Result<C>;
+}
+
+pub trait ReadLinesIntoOnRefSelf {
+    fn read_lines_into<C: FromIterator<String>>(&self) -> crate::Result<C>;
+}
+
+pub trait ReadLinesExtendOnSelf {
+    fn read_lines_extend(self, collection: &mut impl Extend<String>) -> crate::Result<()>;
+}
+
+pub trait ReadLinesExtendOnRefSelf {
+    fn read_lines_extend(&self, collection: &mut impl Extend<String>) -> crate::Result<()>;
+}
+
+pub trait ReadLinesIntoParsedOnSelf {
+    fn read_lines_into_vec_parsed<T: ::std::str::FromStr>(self) -> Result<Vec<T>, crate::parse_lines_error::ParseLinesError<T::Err>>;
+}
+
+pub trait ReadLinesIntoParsedOnRefSelf {
+    fn read_lines_into_vec_parsed<T: ::std::str::FromStr>(&self) -> Result<Vec<T>, crate::parse_lines_error::ParseLinesError<T::Err>>;
+}
+
+pub trait ReadLinesIntoVecBytesOnSelf {
+    fn read_lines_into_vec_bytes(self) -> crate::Result<Vec<Vec<u8>>>;
+}
+
+pub trait ReadLinesIntoVecBytesOnRefSelf {
+    fn read_lines_into_vec_bytes(&self) -> crate::Result<Vec<Vec<u8>>>;
+}
+
+pub trait ReadLinesIntoVecBytesWithClipOnSelf {
+    fn read_lines_into_vec_bytes_with_clip(self) -> crate::Result<Vec<Vec<u8>>>;
+}
+
+pub trait ReadLinesIntoVecBytesWithClipOnRefSelf {
+    fn read_lines_into_vec_bytes_with_clip(&self) -> crate::Result<Vec<Vec<u8>>>;
+}
+
+pub trait ReadLinesIntoVecBytesWithTrimOnSelf {
+    fn read_lines_into_vec_bytes_with_trim(self) -> crate::Result<Vec<Vec<u8>>>;
+}
+
+pub trait ReadLinesIntoVecBytesWithTrimOnRefSelf {
+    fn read_lines_into_vec_bytes_with_trim(&self) -> crate::Result<Vec<Vec<u8>>>;
+}
+
+pub trait ReadLastLinesIntoStringsOnSelf {
+    fn read_last_lines_into_vec_string(self, n: usize) -> crate::Result<Vec<String>>;
+}
+
+pub trait ReadLastLinesIntoStringsOnRefSelf {
+    fn read_last_lines_into_vec_string(&self, n: usize) -> crate::Result<Vec<String>>;
+}
+
+pub trait ReadLastLinesIntoStringsWithClipOnSelf {
+    fn read_last_lines_into_vec_string_with_clip(self, n: usize) -> crate::Result<Vec<String>>;
+}
+
+pub trait ReadLastLinesIntoStringsWithClipOnRefSelf {
+    fn read_last_lines_into_vec_string_with_clip(&self, n: usize) -> crate::Result<Vec<String>>;
+}
+
+pub trait ReadLastLinesIntoStringsWithTrimOnSelf {
+    fn read_last_lines_into_vec_string_with_trim(self, n: usize) -> crate::Result<Vec<String>>;
+}
+
+pub trait ReadLastLinesIntoStringsWithTrimOnRefSelf {
+    fn read_last_lines_into_vec_string_with_trim(&self, n: usize) -> crate::Result<Vec<String>>;
+}
+
+pub trait FollowOnRefSelf {
+    fn follow(&self) -> crate::follow::Follow;
+}
+
+pub trait ReadLinesFromOffsetOnRefSelf {
+    fn read_lines_from_offset(&self, checkpoint: crate::checkpoint::Checkpoint) -> crate::Result<(Vec<String>, crate::checkpoint::Checkpoint)>;
+}
+
+pub trait RevLinesOnSelf {
+    fn rev_lines(self) -> crate::iter::RevLines<Self> where Self: Sized;
+}
+
+pub trait RevLinesOnRefSelf {
+    fn rev_lines(&self) -> crate::Result<crate::iter::RevLines<::std::io::BufReader<::std::fs::File>>>;
+}
+
+pub trait RevLinesWithClipOnSelf {
+    fn rev_lines_with_clip(self) -> crate::iter::RevLinesWithClip<Self> where Self: Sized;
+}
+
+pub trait RevLinesWithClipOnRefSelf {
+    fn rev_lines_with_clip(&self) -> crate::Result<crate::iter::RevLinesWithClip<::std::io::BufReader<::std::fs::File>>>;
 }